@@ -12,6 +12,7 @@ use crate::rules::{
 pub(crate) fn bindings(checker: &Checker) {
     if !checker.any_enabled(&[
         Rule::AssignmentInAssert,
+        Rule::AssignmentUsedOnlyInAssert,
         Rule::InvalidAllFormat,
         Rule::InvalidAllObject,
         Rule::NonAsciiName,
@@ -105,6 +106,12 @@ pub(crate) fn bindings(checker: &Checker) {
                 checker.report_diagnostic(diagnostic);
             }
         }
+        if checker.enabled(Rule::AssignmentUsedOnlyInAssert) {
+            if let Some(diagnostic) = ruff::rules::assignment_used_only_in_assert(checker, binding)
+            {
+                checker.report_diagnostic(diagnostic);
+            }
+        }
         if checker.enabled(Rule::PytestUnittestRaisesAssertion) {
             if let Some(diagnostic) =
                 flake8_pytest_style::rules::unittest_raises_assertion_binding(checker, binding)
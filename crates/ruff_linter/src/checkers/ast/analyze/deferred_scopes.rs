@@ -27,11 +27,14 @@ pub(crate) fn deferred_scopes(checker: &Checker) {
         Rule::MutableClassDefault,
         Rule::MutableDataclassDefault,
         Rule::NoSelfUse,
+        Rule::OverloadWithoutImplementation,
+        Rule::ParameterReassignment,
         Rule::RedefinedArgumentFromLocal,
         Rule::RedefinedWhileUnused,
         Rule::RuntimeImportInTypeCheckingBlock,
         Rule::SingledispatchMethod,
         Rule::SingledispatchmethodFunction,
+        Rule::TooManyGlobalStatements,
         Rule::TooManyLocals,
         Rule::TypingOnlyFirstPartyImport,
         Rule::TypingOnlyStandardLibraryImport,
@@ -159,11 +162,16 @@ pub(crate) fn deferred_scopes(checker: &Checker) {
         if checker.enabled(Rule::ImportShadowedByLoopVar) {
             for (name, binding_id) in scope.bindings() {
                 for shadow in checker.semantic.shadowed_bindings(scope_id, binding_id) {
-                    // If the shadowing binding isn't a loop variable, abort.
+                    // If the shadowing binding isn't a loop variable or a `with` item
+                    // variable, abort.
                     let binding = &checker.semantic.bindings[shadow.binding_id()];
-                    if !binding.kind.is_loop_var() {
+                    let shadowing_kind = if binding.kind.is_loop_var() {
+                        pyflakes::rules::ImportShadowingKind::LoopVar
+                    } else if binding.kind.is_with_item_var() {
+                        pyflakes::rules::ImportShadowingKind::WithItemVar
+                    } else {
                         continue;
-                    }
+                    };
 
                     // If the shadowed binding isn't an import, abort.
                     let shadowed = &checker.semantic.bindings[shadow.shadowed_id()];
@@ -190,6 +198,7 @@ pub(crate) fn deferred_scopes(checker: &Checker) {
                         pyflakes::rules::ImportShadowedByLoopVar {
                             name: name.to_string(),
                             row: checker.compute_source_row(shadowed.start()),
+                            shadowing_kind,
                         },
                         binding.range(),
                     ));
@@ -373,6 +382,12 @@ pub(crate) fn deferred_scopes(checker: &Checker) {
             ruff::rules::asyncio_dangling_binding(scope, checker);
         }
 
+        if checker.enabled(Rule::OverloadWithoutImplementation)
+            && matches!(scope.kind, ScopeKind::Module | ScopeKind::Class(_))
+        {
+            ruff::rules::overload_without_implementation(checker, scope);
+        }
+
         if let Some(class_def) = scope.kind.as_class() {
             if checker.enabled(Rule::BuiltinAttributeShadowing) {
                 flake8_builtins::rules::builtin_attribute_shadowing(
@@ -487,6 +502,10 @@ pub(crate) fn deferred_scopes(checker: &Checker) {
                 pylint::rules::too_many_locals(checker, scope);
             }
 
+            if checker.enabled(Rule::TooManyGlobalStatements) {
+                pylint::rules::too_many_global_statements(checker, scope);
+            }
+
             if checker.enabled(Rule::SingledispatchMethod) {
                 pylint::rules::singledispatch_method(checker, scope);
             }
@@ -499,6 +518,10 @@ pub(crate) fn deferred_scopes(checker: &Checker) {
                 pylint::rules::bad_staticmethod_argument(checker, scope);
             }
 
+            if checker.enabled(Rule::ParameterReassignment) {
+                ruff::rules::parameter_reassignment(checker, scope_id, scope);
+            }
+
             if checker.any_enabled(&[
                 Rule::InvalidFirstArgumentNameForClassMethod,
                 Rule::InvalidFirstArgumentNameForMethod,
@@ -4,7 +4,7 @@ use ruff_text_size::Ranged;
 use crate::checkers::ast::Checker;
 use crate::registry::Rule;
 use crate::rules::{
-    flake8_bandit, flake8_blind_except, flake8_bugbear, flake8_builtins, pycodestyle, pylint,
+    flake8_bandit, flake8_blind_except, flake8_bugbear, flake8_builtins, pycodestyle, pylint, ruff,
 };
 
 /// Run lint rules over an [`ExceptHandler`] syntax node.
@@ -41,6 +41,9 @@ pub(crate) fn except_handler(except_handler: &ExceptHandler, checker: &Checker)
                     body,
                 );
             }
+            if checker.enabled(Rule::BaseExceptionCaught) {
+                ruff::rules::base_exception_caught(checker, type_.as_deref(), name.as_deref(), body);
+            }
             if checker.enabled(Rule::TryExceptPass) {
                 flake8_bandit::rules::try_except_pass(
                     checker,
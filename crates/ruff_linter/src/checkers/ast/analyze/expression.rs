@@ -226,6 +226,9 @@ pub(crate) fn expression(expr: &Expr, checker: &Checker) {
                     if checker.enabled(Rule::RegexFlagAlias) {
                         refurb::rules::regex_flag_alias(checker, expr);
                     }
+                    if checker.enabled(Rule::InvalidSelfOutsideClass) {
+                        ruff::rules::invalid_self_outside_class(checker, expr);
+                    }
                     if checker.enabled(Rule::Airflow3Removal) {
                         airflow::rules::airflow_3_removal_expr(checker, expr);
                     }
@@ -371,6 +374,10 @@ pub(crate) fn expression(expr: &Expr, checker: &Checker) {
                 ]) {
                     flake8_bandit::rules::suspicious_function_reference(checker, expr);
                 }
+
+                if checker.enabled(Rule::InvalidSelfOutsideClass) {
+                    ruff::rules::invalid_self_outside_class(checker, expr);
+                }
             }
 
             // Ex) typing.List[...]
@@ -712,6 +719,9 @@ pub(crate) fn expression(expr: &Expr, checker: &Checker) {
             if checker.enabled(Rule::MutableContextvarDefault) {
                 flake8_bugbear::rules::mutable_contextvar_default(checker, call);
             }
+            if checker.enabled(Rule::MutuallyExclusiveKeywordArguments) {
+                flake8_bugbear::rules::mutually_exclusive_keyword_arguments(checker, call);
+            }
             if checker.enabled(Rule::UnnecessaryDictKwargs) {
                 flake8_pie::rules::unnecessary_dict_kwargs(checker, call);
             }
@@ -840,6 +850,9 @@ pub(crate) fn expression(expr: &Expr, checker: &Checker) {
             if checker.enabled(Rule::UnnecessaryListCall) {
                 flake8_comprehensions::rules::unnecessary_list_call(checker, expr, call);
             }
+            if checker.enabled(Rule::DictCallWithDoubleStarArgs) {
+                ruff::rules::dict_call_with_double_star_args(checker, call);
+            }
             if checker.enabled(Rule::UnnecessaryCallAroundSorted) {
                 flake8_comprehensions::rules::unnecessary_call_around_sorted(
                     checker, expr, func, args,
@@ -850,6 +863,9 @@ pub(crate) fn expression(expr: &Expr, checker: &Checker) {
                     checker, expr, func, args, keywords,
                 );
             }
+            if checker.enabled(Rule::UnnecessaryIterableCastInCall) {
+                ruff::rules::unnecessary_iterable_cast_in_call(checker, expr, func, args);
+            }
             if checker.enabled(Rule::UnnecessarySubscriptReversal) {
                 flake8_comprehensions::rules::unnecessary_subscript_reversal(checker, call);
             }
@@ -914,6 +930,9 @@ pub(crate) fn expression(expr: &Expr, checker: &Checker) {
             if checker.enabled(Rule::SysExitAlias) {
                 pylint::rules::sys_exit_alias(checker, call);
             }
+            if checker.enabled(Rule::SysExitWithMessageOutsideMain) {
+                ruff::rules::sys_exit_with_message_outside_main(checker, call);
+            }
             if checker.enabled(Rule::BadOpenMode) {
                 pylint::rules::bad_open_mode(checker, call);
             }
@@ -1176,9 +1195,21 @@ pub(crate) fn expression(expr: &Expr, checker: &Checker) {
             if checker.enabled(Rule::FalsyDictGetFallback) {
                 ruff::rules::falsy_dict_get_fallback(checker, expr);
             }
+            if checker.enabled(Rule::UnnecessaryDictGetNoneDefault) {
+                ruff::rules::unnecessary_dict_get_none_default(checker, expr);
+            }
             if checker.enabled(Rule::UnnecessaryRound) {
                 ruff::rules::unnecessary_round(checker, call);
             }
+            if checker.enabled(Rule::MutablePartialArgument) {
+                ruff::rules::mutable_partial_argument(checker, call);
+            }
+            if checker.enabled(Rule::RedundantCodecRoundtrip) {
+                ruff::rules::redundant_codec_roundtrip(checker, call);
+            }
+            if checker.enabled(Rule::ExecOrEvalSyntaxError) {
+                ruff::rules::exec_or_eval_syntax_error(checker, call);
+            }
             if checker.enabled(Rule::UnnecessaryEmptyIterableWithinDequeCall) {
                 ruff::rules::unnecessary_literal_within_deque_call(checker, call);
             }
@@ -1205,11 +1236,17 @@ pub(crate) fn expression(expr: &Expr, checker: &Checker) {
             if checker.enabled(Rule::UnnecessarySpread) {
                 flake8_pie::rules::unnecessary_spread(checker, dict);
             }
+            if checker.enabled(Rule::UnhashableKeyOrElement) {
+                ruff::rules::unhashable_dict_key(checker, dict);
+            }
         }
         Expr::Set(set) => {
             if checker.enabled(Rule::DuplicateValue) {
                 flake8_bugbear::rules::duplicate_value(checker, set);
             }
+            if checker.enabled(Rule::UnhashableKeyOrElement) {
+                ruff::rules::unhashable_set_element(checker, set);
+            }
         }
         Expr::Yield(_) => {
             if checker.enabled(Rule::YieldInInit) {
@@ -1224,6 +1261,11 @@ pub(crate) fn expression(expr: &Expr, checker: &Checker) {
                 pylint::rules::yield_from_in_async_function(checker, yield_from);
             }
         }
+        Expr::Await(await_expr) => {
+            if checker.enabled(Rule::AwaitNonAwaitable) {
+                ruff::rules::await_non_awaitable(checker, await_expr);
+            }
+        }
         Expr::FString(f_string_expr @ ast::ExprFString { value, .. }) => {
             if checker.enabled(Rule::FStringMissingPlaceholders) {
                 pyflakes::rules::f_string_missing_placeholders(checker, f_string_expr);
@@ -1378,6 +1420,9 @@ pub(crate) fn expression(expr: &Expr, checker: &Checker) {
             if checker.enabled(Rule::HardcodedSQLExpression) {
                 flake8_bandit::rules::hardcoded_sql_expression(checker, expr);
             }
+            if checker.enabled(Rule::PathConstructorConcatenation) {
+                ruff::rules::path_constructor_concatenation(checker, expr);
+            }
         }
         Expr::BinOp(ast::ExprBinOp {
             op: Operator::BitOr,
@@ -1504,15 +1549,24 @@ pub(crate) fn expression(expr: &Expr, checker: &Checker) {
             if checker.enabled(Rule::NanComparison) {
                 pylint::rules::nan_comparison(checker, left, comparators);
             }
+            if checker.enabled(Rule::TautologicalChainedComparison) {
+                pylint::rules::tautological_chained_comparison(checker, compare);
+            }
             if checker.enabled(Rule::InEmptyCollection) {
                 ruff::rules::in_empty_collection(checker, compare);
             }
+            if checker.enabled(Rule::IncompatibleContainerComparison) {
+                ruff::rules::incompatible_container_comparison(checker, compare);
+            }
             if checker.enabled(Rule::InDictKeys) {
                 flake8_simplify::rules::key_in_dict_compare(checker, compare);
             }
             if checker.enabled(Rule::YodaConditions) {
                 flake8_simplify::rules::yoda_conditions(checker, expr, left, ops, comparators);
             }
+            if checker.enabled(Rule::LenCompareToZero) {
+                ruff::rules::len_compare_to_zero(checker, expr, left, ops, comparators);
+            }
             if checker.enabled(Rule::PandasNuniqueConstantSeriesCheck) {
                 pandas_vet::rules::nunique_constant_series_check(
                     checker,
@@ -1586,6 +1640,9 @@ pub(crate) fn expression(expr: &Expr, checker: &Checker) {
             if checker.enabled(Rule::SliceToRemovePrefixOrSuffix) {
                 refurb::rules::slice_to_remove_affix_expr(checker, if_exp);
             }
+            if checker.enabled(Rule::NestedTernary) {
+                ruff::rules::nested_ternary(checker, if_exp);
+            }
         }
         Expr::ListComp(
             comp @ ast::ExprListComp {
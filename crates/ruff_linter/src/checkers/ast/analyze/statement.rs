@@ -105,6 +105,9 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
             if checker.enabled(Rule::InvalidBoolReturnType) {
                 pylint::rules::invalid_bool_return(checker, function_def);
             }
+            if checker.enabled(Rule::ExitSuppressesException) {
+                ruff::rules::exit_suppresses_exception(checker, function_def);
+            }
             if checker.enabled(Rule::InvalidLengthReturnType) {
                 pylint::rules::invalid_length_return(checker, function_def);
             }
@@ -117,9 +120,15 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
             if checker.enabled(Rule::InvalidHashReturnType) {
                 pylint::rules::invalid_hash_return(checker, function_def);
             }
+            if checker.enabled(Rule::InvalidReprReturnType) {
+                pylint::rules::invalid_repr_return(checker, function_def);
+            }
             if checker.enabled(Rule::InvalidStrReturnType) {
                 pylint::rules::invalid_str_return(checker, function_def);
             }
+            if checker.enabled(Rule::ReturnInNoneReturnFunction) {
+                ruff::rules::return_in_none_return_function(checker, function_def);
+            }
             if checker.enabled(Rule::InvalidFunctionName) {
                 if let Some(diagnostic) = pep8_naming::rules::invalid_function_name(
                     stmt,
@@ -266,6 +275,13 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
                     checker.report_diagnostic(diagnostic);
                 }
             }
+            if checker.enabled(Rule::InconsistentReturnStatements) {
+                if let Some(diagnostic) =
+                    pylint::rules::inconsistent_return_statements(stmt, body)
+                {
+                    checker.report_diagnostic(diagnostic);
+                }
+            }
             if checker.enabled(Rule::TooManyBranches) {
                 if let Some(diagnostic) = pylint::rules::too_many_branches(
                     stmt,
@@ -366,6 +382,12 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
             if checker.enabled(Rule::PostInitDefault) {
                 ruff::rules::post_init_default(checker, function_def);
             }
+            if checker.enabled(Rule::MisplacedDescriptorDecorator) {
+                ruff::rules::misplaced_descriptor_decorator(checker, decorator_list);
+            }
+            if checker.enabled(Rule::DuplicateDecorator) {
+                ruff::rules::duplicate_decorator(checker, decorator_list);
+            }
             if checker.enabled(Rule::PytestParameterWithDefaultArgument) {
                 flake8_pytest_style::rules::parameter_with_default_argument(checker, function_def);
             }
@@ -379,10 +401,13 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
                 pep8_naming::rules::invalid_argument_name_function(checker, function_def);
             }
         }
-        Stmt::Return(_) => {
+        Stmt::Return(return_stmt) => {
             if checker.enabled(Rule::ReturnInInit) {
                 pylint::rules::return_in_init(checker, stmt);
             }
+            if checker.enabled(Rule::NotImplementedReturnValue) {
+                ruff::rules::not_implemented_return_value(checker, return_stmt);
+            }
         }
         Stmt::ClassDef(
             class_def @ ast::StmtClassDef {
@@ -420,6 +445,9 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
             if checker.enabled(Rule::EqWithoutHash) {
                 pylint::rules::object_without_hash_method(checker, class_def);
             }
+            if checker.enabled(Rule::MissingSuperCall) {
+                pylint::rules::missing_super_call(checker, class_def);
+            }
             if checker.enabled(Rule::ClassAsDataStructure) {
                 flake8_bugbear::rules::class_as_data_structure(checker, class_def);
             }
@@ -502,6 +530,9 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
             if checker.enabled(Rule::PytestIncorrectMarkParenthesesStyle) {
                 flake8_pytest_style::rules::marks(checker, decorator_list);
             }
+            if checker.enabled(Rule::DuplicateDecorator) {
+                ruff::rules::duplicate_decorator(checker, decorator_list);
+            }
             if checker.enabled(Rule::DuplicateClassFieldDefinition) {
                 flake8_pie::rules::duplicate_class_field_definition(checker, body);
             }
@@ -544,6 +575,9 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
             if checker.enabled(Rule::DataclassEnum) {
                 ruff::rules::dataclass_enum(checker, class_def);
             }
+            if checker.enabled(Rule::EnumMixinBaseOrder) {
+                ruff::rules::enum_mixin_base_order(checker, class_def);
+            }
             if checker.enabled(Rule::NonPEP695GenericClass) {
                 pyupgrade::rules::non_pep695_generic_class(checker, class_def);
             }
@@ -1050,6 +1084,12 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
             if checker.enabled(Rule::MisplacedBareRaise) {
                 pylint::rules::misplaced_bare_raise(checker, raise);
             }
+            if checker.enabled(Rule::RaiseInDel) {
+                ruff::rules::raise_in_del(checker, raise);
+            }
+            if checker.enabled(Rule::RaiseFromNone) {
+                ruff::rules::raise_from_none(checker, raise);
+            }
         }
         Stmt::AugAssign(aug_assign @ ast::StmtAugAssign { target, .. }) => {
             if checker.enabled(Rule::GlobalStatement) {
@@ -1196,9 +1236,15 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
             if checker.enabled(Rule::IfKeyInDictDel) {
                 ruff::rules::if_key_in_dict_del(checker, if_);
             }
+            if checker.enabled(Rule::IfElseBlockInsteadOfGetattr) {
+                ruff::rules::if_else_block_instead_of_getattr(checker, if_);
+            }
             if checker.enabled(Rule::NeedlessElse) {
                 ruff::rules::needless_else(checker, if_.into());
             }
+            if checker.enabled(Rule::DebugGuardedBlock) {
+                ruff::rules::debug_guarded_block(checker, if_);
+            }
         }
         Stmt::Assert(
             assert_stmt @ ast::StmtAssert {
@@ -1232,6 +1278,9 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
             if checker.enabled(Rule::AssertOnStringLiteral) {
                 pylint::rules::assert_on_string_literal(checker, test);
             }
+            if checker.enabled(Rule::AssertOnConstant) {
+                ruff::rules::assert_on_constant(checker, stmt, test);
+            }
             if checker.enabled(Rule::InvalidMockAccess) {
                 pygrep_hooks::rules::non_existent_mock_method(checker, test);
             }
@@ -1277,6 +1326,9 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
             if checker.enabled(Rule::CancelScopeNoCheckpoint) {
                 flake8_async::rules::cancel_scope_no_checkpoint(checker, with_stmt, items);
             }
+            if checker.enabled(Rule::ReturnedClosedFile) {
+                ruff::rules::returned_closed_file(checker, with_stmt);
+            }
         }
         Stmt::While(while_stmt @ ast::StmtWhile { body, orelse, .. }) => {
             if checker.enabled(Rule::TooManyNestedBlocks) {
@@ -1418,6 +1470,9 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
             ]) {
                 flake8_bugbear::rules::duplicate_exceptions(checker, handlers);
             }
+            if checker.enabled(Rule::BadExceptOrder) {
+                pylint::rules::bad_except_order(checker, handlers);
+            }
             if checker.enabled(Rule::RedundantTupleInExceptionHandler) {
                 flake8_bugbear::rules::redundant_tuple_in_exception_handler(checker, handlers);
             }
@@ -1509,6 +1564,9 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
                     checker.report_diagnostic(diagnostic);
                 }
             }
+            if checker.enabled(Rule::NoneReturningMethodAssignment) {
+                ruff::rules::none_returning_method_assignment(checker, value);
+            }
             if checker
                 .settings
                 .rules
@@ -1532,6 +1590,9 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
             if checker.settings.rules.enabled(Rule::TypeBivariance) {
                 pylint::rules::type_bivariance(checker, value);
             }
+            if checker.enabled(Rule::TypeVarBoundAndConstraints) {
+                ruff::rules::type_var_bound_and_constraints(checker, value);
+            }
             if checker.enabled(Rule::NonAugmentedAssignment) {
                 pylint::rules::non_augmented_assignment(checker, assign);
             }
@@ -1658,6 +1719,11 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
                 if checker.enabled(Rule::TSuffixedTypeAlias) {
                     flake8_pyi::rules::t_suffixed_type_alias(checker, target);
                 }
+                if let Some(value) = value {
+                    if checker.enabled(Rule::InvalidTypeAliasValue) {
+                        ruff::rules::invalid_type_alias_value(checker, value);
+                    }
+                }
             } else if checker
                 .semantic
                 .match_typing_expr(helpers::map_subscript(annotation), "Final")
@@ -1686,6 +1752,9 @@ pub(crate) fn statement(stmt: &Stmt, checker: &mut Checker) {
             if checker.enabled(Rule::DeleteFullSlice) {
                 refurb::rules::delete_full_slice(checker, delete);
             }
+            if checker.enabled(Rule::DeleteUnassignedAttribute) {
+                ruff::rules::delete_unassigned_attribute(checker, delete);
+            }
         }
         Stmt::Expr(expr @ ast::StmtExpr { value, range: _ }) => {
             if checker.enabled(Rule::UselessComparison) {
@@ -39,4 +39,7 @@ pub(crate) fn string_like(string_like: StringLike, checker: &Checker) {
     if checker.enabled(Rule::InvalidEscapeSequence) {
         pycodestyle::rules::invalid_escape_sequence(checker, string_like);
     }
+    if checker.enabled(Rule::WindowsPathStringLiteral) {
+        ruff::rules::windows_path_string_literal(checker, string_like);
+    }
 }
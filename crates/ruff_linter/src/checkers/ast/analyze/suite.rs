@@ -4,6 +4,7 @@ use crate::checkers::ast::Checker;
 use crate::codes::Rule;
 use crate::rules::flake8_pie;
 use crate::rules::refurb;
+use crate::rules::ruff;
 
 /// Run lint rules over a suite of [`Stmt`] syntax nodes.
 pub(crate) fn suite(suite: &[Stmt], checker: &Checker) {
@@ -13,4 +14,7 @@ pub(crate) fn suite(suite: &[Stmt], checker: &Checker) {
     if checker.enabled(Rule::RepeatedGlobal) {
         refurb::rules::repeated_global(checker, suite);
     }
+    if checker.enabled(Rule::UnreachableAssertNever) {
+        ruff::rules::unreachable_assert_never(checker, suite);
+    }
 }
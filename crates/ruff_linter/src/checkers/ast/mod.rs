@@ -71,7 +71,7 @@ use crate::rules::pyflakes::rules::{
     LateFutureImport, ReturnOutsideFunction, YieldOutsideFunction,
 };
 use crate::rules::pylint::rules::{AwaitOutsideAsync, LoadBeforeGlobalDeclaration};
-use crate::rules::{flake8_pyi, flake8_type_checking, pyflakes, pyupgrade};
+use crate::rules::{flake8_pyi, flake8_type_checking, pyflakes, pyupgrade, ruff};
 use crate::settings::{flags, LinterSettings, TargetVersion};
 use crate::{docstrings, noqa, Locator};
 
@@ -229,6 +229,8 @@ pub(crate) struct Checker<'a> {
     flake8_bugbear_seen: RefCell<FxHashSet<TextRange>>,
     /// The end offset of the last visited statement.
     last_stmt_end: TextSize,
+    /// The start offset of the first statement in each enclosing body currently being visited.
+    first_stmt_in_body_stack: Vec<TextSize>,
     /// A state describing if a docstring is expected or not.
     docstring_state: DocstringState,
     /// The target [`PythonVersion`] for version-dependent checks.
@@ -283,6 +285,7 @@ impl<'a> Checker<'a> {
             cell_offsets,
             notebook_index,
             last_stmt_end: TextSize::default(),
+            first_stmt_in_body_stack: Vec::new(),
             docstring_state: DocstringState::default(),
             target_version,
             semantic_checker: SemanticSyntaxChecker::new(),
@@ -379,6 +382,13 @@ impl<'a> Checker<'a> {
         self.indexer.comment_ranges()
     }
 
+    /// Returns `true` if `stmt` is the first statement in the body currently being visited.
+    pub(crate) fn is_first_statement_in_body(&self, stmt: &Stmt) -> bool {
+        self.first_stmt_in_body_stack
+            .last()
+            .is_some_and(|start| *start == stmt.start())
+    }
+
     /// Push a new [`Diagnostic`] to the collection in the [`Checker`]
     pub(crate) fn report_diagnostic(&self, diagnostic: Diagnostic) {
         let mut diagnostics = self.diagnostics.borrow_mut();
@@ -423,6 +433,31 @@ impl<'a> Checker<'a> {
         self.locator.contents()
     }
 
+    /// Return the source code for the given [`TextRange`].
+    pub(crate) fn source_slice(&self, range: TextRange) -> &'a str {
+        self.locator.slice(range)
+    }
+
+    /// Like [`Checker::source_slice`], but trims leading and trailing whitespace.
+    ///
+    /// When the file being checked is a Jupyter notebook, the range is first clamped to the
+    /// boundaries of the cell containing its start, so that a range spanning a cell boundary
+    /// doesn't pull in the invisible marker text separating concatenated cells.
+    pub(crate) fn source_slice_trimmed(&self, range: TextRange) -> &'a str {
+        self.locator.slice(self.clamp_to_cell(range)).trim()
+    }
+
+    /// Clamp `range` to the end of the cell containing its start, if this file is a notebook.
+    fn clamp_to_cell(&self, range: TextRange) -> TextRange {
+        let Some(cell_offsets) = self.cell_offsets else {
+            return range;
+        };
+        let Some(cell_range) = cell_offsets.containing_range(range.start()) else {
+            return range;
+        };
+        TextRange::new(range.start(), range.end().min(cell_range.end()))
+    }
+
     /// The [`Stylist`] for the current file, which detects the current line ending, quote, and
     /// indentation style.
     pub(crate) const fn stylist(&self) -> &'a Stylist<'a> {
@@ -535,6 +570,15 @@ impl<'a> Checker<'a> {
         self.target_version.linter_version()
     }
 
+    /// Return `true` if preview mode is enabled for the file being checked.
+    ///
+    /// This respects the per-file overrides in [`LinterSettings::per_file_preview`], falling back
+    /// on the global [`LinterSettings::preview`] setting if none of the override patterns match
+    /// the file currently being checked.
+    pub(crate) fn preview_enabled(&self) -> bool {
+        self.settings.resolve_preview(self.path).is_enabled()
+    }
+
     fn with_semantic_checker(&mut self, f: impl FnOnce(&mut SemanticSyntaxChecker, &Checker)) {
         let mut checker = std::mem::take(&mut self.semantic_checker);
         f(&mut checker, self);
@@ -653,7 +697,7 @@ impl SemanticSyntaxContext for Checker<'_> {
             | SemanticSyntaxErrorKind::AsyncComprehensionInSyncComprehension(_)
             | SemanticSyntaxErrorKind::DuplicateParameter(_)
             | SemanticSyntaxErrorKind::NonlocalDeclarationAtModuleLevel => {
-                if is_semantic_errors_enabled(self.settings) {
+                if is_semantic_errors_enabled(self) {
                     self.semantic_errors.borrow_mut().push(error);
                 }
             }
@@ -1063,6 +1107,8 @@ impl<'a> Visitor<'a> for Checker<'a> {
                     }
                 }
                 if let Some(expr) = returns {
+                    let snapshot = self.semantic.flags;
+                    self.semantic.flags |= SemanticModelFlags::RETURN_ANNOTATION;
                     if singledispatch {
                         self.visit_runtime_required_annotation(expr);
                     } else {
@@ -1078,6 +1124,7 @@ impl<'a> Visitor<'a> for Checker<'a> {
                             }
                         }
                     }
+                    self.semantic.flags = snapshot;
                 }
 
                 let definition = docstrings::extraction::extract_definition(
@@ -1549,6 +1596,11 @@ impl<'a> Visitor<'a> for Checker<'a> {
                                 .match_typing_qualified_name(&qualified_name, "TypedDict")
                             {
                                 Some(typing::Callable::TypedDict)
+                            } else if self
+                                .semantic
+                                .match_typing_qualified_name(&qualified_name, "assert_type")
+                            {
+                                Some(typing::Callable::AssertType)
                             } else if matches!(
                                 qualified_name.segments(),
                                 [
@@ -1613,6 +1665,25 @@ impl<'a> Visitor<'a> for Checker<'a> {
                             }
                         }
                     }
+                    Some(typing::Callable::AssertType) => {
+                        // Ex) `typing.assert_type(val, int)` — the first argument is a regular
+                        // expression, while the second is a type definition.
+                        for (i, arg) in arguments.arguments_source_order().enumerate() {
+                            match (i, arg) {
+                                (1, ArgOrKeyword::Arg(arg)) => self.visit_type_definition(arg),
+                                (_, ArgOrKeyword::Arg(arg)) => self.visit_non_type_definition(arg),
+                                (_, ArgOrKeyword::Keyword(Keyword { arg, value, .. })) => {
+                                    if let Some(id) = arg {
+                                        if id == "typ" {
+                                            self.visit_type_definition(value);
+                                        } else {
+                                            self.visit_non_type_definition(value);
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
                     Some(typing::Callable::TypeVar) => {
                         let mut args = arguments.args.iter();
                         if let Some(arg) = args.next() {
@@ -1811,6 +1882,10 @@ impl<'a> Visitor<'a> for Checker<'a> {
                         }
                         // Ex) Optional[int]
                         Some(typing::SubscriptKind::Generic) => {
+                            if self.semantic.in_type_alias_value() {
+                                self.semantic.flags |=
+                                    SemanticModelFlags::TYPE_ALIAS_SUBSCRIPT_SLICE;
+                            }
                             self.visit_type_definition(slice);
                             self.visit_expr_context(ctx);
                         }
@@ -1830,7 +1905,15 @@ impl<'a> Visitor<'a> for Checker<'a> {
                                     self.visit_type_definition(expr);
                                 }
                                 for expr in iter {
-                                    self.visit_non_type_definition(expr);
+                                    if flake8_type_checking::helpers::is_runtime_required_annotated_metadata(
+                                        expr,
+                                        &self.settings.flake8_type_checking.runtime_required_annotated_metadata,
+                                        &self.semantic,
+                                    ) {
+                                        self.visit_runtime_required_annotation(expr);
+                                    } else {
+                                        self.visit_non_type_definition(expr);
+                                    }
                                 }
                                 self.visit_expr_context(ctx);
                             } else {
@@ -2025,9 +2108,15 @@ impl<'a> Visitor<'a> for Checker<'a> {
         analyze::suite(body, self);
 
         // Step 2: Traversal
+        if let Some(first) = body.first() {
+            self.first_stmt_in_body_stack.push(first.start());
+        }
         for stmt in body {
             self.visit_stmt(stmt);
         }
+        if !body.is_empty() {
+            self.first_stmt_in_body_stack.pop();
+        }
     }
 
     fn visit_match_case(&mut self, match_case: &'a MatchCase) {
@@ -2847,6 +2936,13 @@ impl<'a> Checker<'a> {
             })
             .collect();
 
+        let has_dunder_all = !definitions.is_empty();
+        let exported: Vec<&str> = definitions
+            .iter()
+            .flat_map(|definition| definition.names())
+            .map(ruff_python_semantic::all::DunderAllName::name)
+            .collect();
+
         for definition in definitions {
             for export in definition.names() {
                 let (name, range) = (export.name(), export.range());
@@ -2871,7 +2967,7 @@ impl<'a> Checker<'a> {
                         }
                     } else {
                         if self.enabled(Rule::UndefinedExport) {
-                            if is_undefined_export_in_dunder_init_enabled(self.settings)
+                            if is_undefined_export_in_dunder_init_enabled(self)
                                 || !self.path.ends_with("__init__.py")
                             {
                                 self.diagnostics.get_mut().push(
@@ -2890,6 +2986,10 @@ impl<'a> Checker<'a> {
             }
         }
 
+        if has_dunder_all && self.enabled(Rule::UndeclaredPublicName) {
+            ruff::rules::undeclared_public_names(self, &exported);
+        }
+
         self.semantic.restore(snapshot);
     }
 }
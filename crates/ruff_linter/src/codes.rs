@@ -211,6 +211,7 @@ pub fn code_to_rule(linter: Linter, code: &str) -> Option<(RuleGroup, Rule)> {
         (Pylint, "E0303") => (RuleGroup::Stable, rules::pylint::rules::InvalidLengthReturnType),
         (Pylint, "E0304") => (RuleGroup::Preview, rules::pylint::rules::InvalidBoolReturnType),
         (Pylint, "E0305") => (RuleGroup::Stable, rules::pylint::rules::InvalidIndexReturnType),
+        (Pylint, "E0306") => (RuleGroup::Preview, rules::pylint::rules::InvalidReprReturnType),
         (Pylint, "E0307") => (RuleGroup::Stable, rules::pylint::rules::InvalidStrReturnType),
         (Pylint, "E0308") => (RuleGroup::Stable, rules::pylint::rules::InvalidBytesReturnType),
         (Pylint, "E0309") => (RuleGroup::Stable, rules::pylint::rules::InvalidHashReturnType),
@@ -251,10 +252,12 @@ pub fn code_to_rule(linter: Linter, code: &str) -> Option<(RuleGroup, Rule)> {
         (Pylint, "R0915") => (RuleGroup::Stable, rules::pylint::rules::TooManyStatements),
         (Pylint, "R0916") => (RuleGroup::Preview, rules::pylint::rules::TooManyBooleanExpressions),
         (Pylint, "R0917") => (RuleGroup::Preview, rules::pylint::rules::TooManyPositionalArguments),
+        (Pylint, "R0918") => (RuleGroup::Preview, rules::pylint::rules::TooManyGlobalStatements),
         (Pylint, "R1701") => (RuleGroup::Removed, rules::pylint::rules::RepeatedIsinstanceCalls),
         (Pylint, "R1702") => (RuleGroup::Preview, rules::pylint::rules::TooManyNestedBlocks),
         (Pylint, "R1704") => (RuleGroup::Stable, rules::pylint::rules::RedefinedArgumentFromLocal),
         (Pylint, "R1706") => (RuleGroup::Removed, rules::pylint::rules::AndOrTernary),
+        (Pylint, "R1710") => (RuleGroup::Preview, rules::pylint::rules::InconsistentReturnStatements),
         (Pylint, "R1711") => (RuleGroup::Stable, rules::pylint::rules::UselessReturn),
         (Pylint, "R1714") => (RuleGroup::Stable, rules::pylint::rules::RepeatedEqualityComparison),
         (Pylint, "R1722") => (RuleGroup::Stable, rules::pylint::rules::SysExitAlias),
@@ -263,6 +266,7 @@ pub fn code_to_rule(linter: Linter, code: &str) -> Option<(RuleGroup, Rule)> {
         (Pylint, "R1733") => (RuleGroup::Preview, rules::pylint::rules::UnnecessaryDictIndexLookup),
         (Pylint, "R1736") => (RuleGroup::Stable, rules::pylint::rules::UnnecessaryListIndexLookup),
         (Pylint, "R2004") => (RuleGroup::Stable, rules::pylint::rules::MagicValueComparison),
+        (Pylint, "R6202") => (RuleGroup::Preview, rules::pylint::rules::TautologicalChainedComparison),
         (Pylint, "R2044") => (RuleGroup::Stable, rules::pylint::rules::EmptyComment),
         (Pylint, "R5501") => (RuleGroup::Stable, rules::pylint::rules::CollapsibleElseIf),
         (Pylint, "R6104") => (RuleGroup::Preview, rules::pylint::rules::NonAugmentedAssignment),
@@ -279,6 +283,7 @@ pub fn code_to_rule(linter: Linter, code: &str) -> Option<(RuleGroup, Rule)> {
         (Pylint, "W0131") => (RuleGroup::Stable, rules::pylint::rules::NamedExprWithoutContext),
         (Pylint, "W0133") => (RuleGroup::Stable, rules::pylint::rules::UselessExceptionStatement),
         (Pylint, "W0211") => (RuleGroup::Stable, rules::pylint::rules::BadStaticmethodArgument),
+        (Pylint, "W0231") => (RuleGroup::Preview, rules::pylint::rules::MissingSuperCall),
         (Pylint, "W0244") => (RuleGroup::Preview, rules::pylint::rules::RedefinedSlotsInSubclass),
         (Pylint, "W0245") => (RuleGroup::Stable, rules::pylint::rules::SuperWithoutBrackets),
         (Pylint, "W0406") => (RuleGroup::Stable, rules::pylint::rules::ImportSelf),
@@ -286,6 +291,7 @@ pub fn code_to_rule(linter: Linter, code: &str) -> Option<(RuleGroup, Rule)> {
         (Pylint, "W0603") => (RuleGroup::Stable, rules::pylint::rules::GlobalStatement),
         (Pylint, "W0604") => (RuleGroup::Stable, rules::pylint::rules::GlobalAtModuleLevel),
         (Pylint, "W0642") => (RuleGroup::Stable, rules::pylint::rules::SelfOrClsAssignment),
+        (Pylint, "W0705") => (RuleGroup::Preview, rules::pylint::rules::BadExceptOrder),
         (Pylint, "W0711") => (RuleGroup::Stable, rules::pylint::rules::BinaryOpException),
         (Pylint, "W1501") => (RuleGroup::Stable, rules::pylint::rules::BadOpenMode),
         (Pylint, "W1507") => (RuleGroup::Stable, rules::pylint::rules::ShallowCopyEnviron),
@@ -357,6 +363,7 @@ pub fn code_to_rule(linter: Linter, code: &str) -> Option<(RuleGroup, Rule)> {
         (Flake8Bugbear, "034") => (RuleGroup::Stable, rules::flake8_bugbear::rules::ReSubPositionalArgs),
         (Flake8Bugbear, "035") => (RuleGroup::Stable, rules::flake8_bugbear::rules::StaticKeyDictComprehension),
         (Flake8Bugbear, "039") => (RuleGroup::Stable, rules::flake8_bugbear::rules::MutableContextvarDefault),
+        (Flake8Bugbear, "040") => (RuleGroup::Preview, rules::flake8_bugbear::rules::MutuallyExclusiveKeywordArguments),
         (Flake8Bugbear, "901") => (RuleGroup::Preview, rules::flake8_bugbear::rules::ReturnInGenerator),
         (Flake8Bugbear, "903") => (RuleGroup::Preview, rules::flake8_bugbear::rules::ClassAsDataStructure),
         (Flake8Bugbear, "904") => (RuleGroup::Stable, rules::flake8_bugbear::rules::RaiseWithoutFromInsideExcept),
@@ -1015,6 +1022,44 @@ pub fn code_to_rule(linter: Linter, code: &str) -> Option<(RuleGroup, Rule)> {
         (Ruff, "058") => (RuleGroup::Preview, rules::ruff::rules::StarmapZip),
         (Ruff, "059") => (RuleGroup::Preview, rules::ruff::rules::UnusedUnpackedVariable),
         (Ruff, "060") => (RuleGroup::Preview, rules::ruff::rules::InEmptyCollection),
+        (Ruff, "061") => (RuleGroup::Preview, rules::ruff::rules::DeleteUnassignedAttribute),
+        (Ruff, "062") => (RuleGroup::Preview, rules::ruff::rules::ComprehensionShadowsParameter),
+        (Ruff, "063") => (RuleGroup::Preview, rules::ruff::rules::NotImplementedReturnValue),
+        (Ruff, "064") => (RuleGroup::Preview, rules::ruff::rules::PathConstructorConcatenation),
+        (Ruff, "065") => (RuleGroup::Preview, rules::ruff::rules::InvalidSelfOutsideClass),
+        (Ruff, "066") => (RuleGroup::Preview, rules::ruff::rules::UnreachableAssertNever),
+        (Ruff, "067") => (RuleGroup::Preview, rules::ruff::rules::DictCallWithDoubleStarArgs),
+        (Ruff, "068") => (RuleGroup::Preview, rules::ruff::rules::AwaitNonAwaitable),
+        (Ruff, "069") => (RuleGroup::Preview, rules::ruff::rules::RaiseInDel),
+        (Ruff, "070") => (RuleGroup::Preview, rules::ruff::rules::OverloadWithoutImplementation),
+        (Ruff, "071") => (RuleGroup::Preview, rules::ruff::rules::UnnecessaryDictGetNoneDefault),
+        (Ruff, "072") => (RuleGroup::Preview, rules::ruff::rules::TypeVarBoundAndConstraints),
+        (Ruff, "073") => (RuleGroup::Preview, rules::ruff::rules::MutablePartialArgument),
+        (Ruff, "074") => (RuleGroup::Preview, rules::ruff::rules::RedundantCodecRoundtrip),
+        (Ruff, "075") => (RuleGroup::Preview, rules::ruff::rules::ExecOrEvalSyntaxError),
+        (Ruff, "076") => (RuleGroup::Preview, rules::ruff::rules::ReturnedClosedFile),
+        (Ruff, "077") => (RuleGroup::Preview, rules::ruff::rules::UndeclaredPublicName),
+        (Ruff, "078") => (RuleGroup::Preview, rules::ruff::rules::MisplacedDescriptorDecorator),
+        (Ruff, "079") => (RuleGroup::Preview, rules::ruff::rules::IncompatibleContainerComparison),
+        (Ruff, "080") => (RuleGroup::Preview, rules::ruff::rules::NoneReturningMethodAssignment),
+        (Ruff, "081") => (RuleGroup::Preview, rules::ruff::rules::InvalidTypeAliasValue),
+        (Ruff, "082") => (RuleGroup::Preview, rules::ruff::rules::WindowsPathStringLiteral),
+        (Ruff, "083") => (RuleGroup::Preview, rules::ruff::rules::SysExitWithMessageOutsideMain),
+        (Ruff, "084") => (RuleGroup::Preview, rules::ruff::rules::AssertOnConstant),
+        (Ruff, "085") => (RuleGroup::Preview, rules::ruff::rules::ReturnInNoneReturnFunction),
+        (Ruff, "086") => (RuleGroup::Preview, rules::ruff::rules::BaseExceptionCaught),
+        (Ruff, "087") => (RuleGroup::Preview, rules::ruff::rules::AssignmentUsedOnlyInAssert),
+        (Ruff, "088") => (RuleGroup::Preview, rules::ruff::rules::UnnecessaryIterableCastInCall),
+        (Ruff, "089") => (RuleGroup::Preview, rules::ruff::rules::IfElseBlockInsteadOfGetattr),
+        (Ruff, "090") => (RuleGroup::Preview, rules::ruff::rules::NestedTernary),
+        (Ruff, "091") => (RuleGroup::Preview, rules::ruff::rules::DebugGuardedBlock),
+        (Ruff, "092") => (RuleGroup::Preview, rules::ruff::rules::DuplicateDecorator),
+        (Ruff, "093") => (RuleGroup::Preview, rules::ruff::rules::ExitSuppressesException),
+        (Ruff, "094") => (RuleGroup::Preview, rules::ruff::rules::LenCompareToZero),
+        (Ruff, "095") => (RuleGroup::Preview, rules::ruff::rules::UnhashableKeyOrElement),
+        (Ruff, "096") => (RuleGroup::Preview, rules::ruff::rules::RaiseFromNone),
+        (Ruff, "097") => (RuleGroup::Preview, rules::ruff::rules::ParameterReassignment),
+        (Ruff, "098") => (RuleGroup::Preview, rules::ruff::rules::EnumMixinBaseOrder),
         (Ruff, "100") => (RuleGroup::Stable, rules::ruff::rules::UnusedNOQA),
         (Ruff, "101") => (RuleGroup::Stable, rules::ruff::rules::RedirectedNOQA),
         (Ruff, "102") => (RuleGroup::Preview, rules::ruff::rules::InvalidRuleCode),
@@ -5,12 +5,16 @@
 //! which specific feature this preview check is for. Having named functions simplifies the promotion:
 //! Simply delete the function and let Rust tell you which checks you have to remove.
 
+use crate::checkers::ast::Checker;
 use crate::settings::LinterSettings;
 
 // https://github.com/astral-sh/ruff/issues/17412
 // https://github.com/astral-sh/ruff/issues/11934
-pub(crate) const fn is_semantic_errors_enabled(settings: &LinterSettings) -> bool {
-    settings.preview.is_enabled()
+//
+// Consults the per-file preview override (if any) via `Checker::preview_enabled`, so that
+// monorepos can roll this check out package-by-package.
+pub(crate) fn is_semantic_errors_enabled(checker: &Checker) -> bool {
+    checker.preview_enabled()
 }
 
 // https://github.com/astral-sh/ruff/pull/16429
@@ -112,8 +116,11 @@ pub(crate) const fn is_support_slices_in_literal_concatenation_enabled(
 }
 
 // https://github.com/astral-sh/ruff/pull/11370
-pub(crate) const fn is_undefined_export_in_dunder_init_enabled(settings: &LinterSettings) -> bool {
-    settings.preview.is_enabled()
+//
+// Consults the per-file preview override (if any) via `Checker::preview_enabled`, so that
+// monorepos can roll this check out package-by-package.
+pub(crate) fn is_undefined_export_in_dunder_init_enabled(checker: &Checker) -> bool {
+    checker.preview_enabled()
 }
 
 // https://github.com/astral-sh/ruff/pull/14236
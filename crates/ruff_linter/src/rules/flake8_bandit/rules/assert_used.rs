@@ -14,7 +14,16 @@ use ruff_text_size::Ranged;
 /// production environments. As such, assertions should not be used for runtime
 /// validation of user input or to enforce  interface constraints.
 ///
-/// Consider raising a meaningful error instead of using `assert`.
+/// Consider raising a meaningful error instead of using `assert`, including
+/// asserts that narrow a type for the type checker (e.g., `assert
+/// isinstance(x, T)`), since those are just as susceptible to being stripped
+/// under `-O` as any other assertion.
+///
+/// This rule intentionally does not distinguish `assert isinstance(...)` from
+/// other assertions, or exempt test files, since test suites commonly rely on
+/// `assert` intentionally. To allow `assert` in test files (or any other
+/// path), add an exemption via [`lint.per-file-ignores`] rather than
+/// disabling the rule outright.
 ///
 /// ## Example
 /// ```python
@@ -38,6 +38,15 @@ use crate::rules::flake8_boolean_trap::helpers::allow_boolean_trap;
 /// ## Options
 /// - `lint.flake8-boolean-trap.extend-allowed-calls`
 ///
+/// This rule flags every bare boolean positional argument, regardless of
+/// whether the callee's corresponding parameter is actually annotated
+/// `bool`; resolving the callee's signature is only reliable for calls to
+/// functions defined in the same module, which would make the rule's
+/// behavior depend on where a function happens to be defined. Pair this
+/// rule with `boolean-type-hint-positional-argument` and
+/// `boolean-default-value-positional-argument`, which flag the
+/// corresponding function definitions, to catch both sides of the trap.
+///
 /// ## References
 /// - [Python documentation: Calls](https://docs.python.org/3/reference/expressions.html#calls)
 /// - [_How to Avoid “The Boolean Trap”_ by Adam Johnson](https://adamj.eu/tech/2021/07/10/python-type-hints-how-to-avoid-the-boolean-trap/)
@@ -19,7 +19,9 @@ use crate::checkers::ast::Checker;
 ///
 /// Instead, refactor the method to depend only on its arguments and not on the
 /// instance of the class, or use the `@lru_cache` decorator on a function
-/// outside of the class.
+/// outside of the class. If the goal is to cache a value derived from `self`
+/// alone, `@functools.cached_property` avoids the leak, since the cached
+/// value is stored on the instance itself and released along with it.
 ///
 /// This rule ignores instance methods on enumeration classes, as enum members
 /// are singletons.
@@ -18,6 +18,7 @@ pub(crate) use loop_iterator_mutation::*;
 pub(crate) use loop_variable_overrides_iterator::*;
 pub(crate) use mutable_argument_default::*;
 pub(crate) use mutable_contextvar_default::*;
+pub(crate) use mutually_exclusive_keyword_arguments::*;
 pub(crate) use no_explicit_stacklevel::*;
 pub(crate) use raise_literal::*;
 pub(crate) use raise_without_from_inside_except::*;
@@ -58,6 +59,7 @@ mod loop_iterator_mutation;
 mod loop_variable_overrides_iterator;
 mod mutable_argument_default;
 mod mutable_contextvar_default;
+mod mutually_exclusive_keyword_arguments;
 mod no_explicit_stacklevel;
 mod raise_literal;
 mod raise_without_from_inside_except;
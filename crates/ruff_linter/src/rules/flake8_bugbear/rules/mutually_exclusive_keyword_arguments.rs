@@ -0,0 +1,77 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::{self as ast};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for calls that pass two keyword arguments that are documented as
+/// mutually exclusive.
+///
+/// ## Why is this bad?
+/// Some standard-library callables raise a `TypeError` or `ValueError` at
+/// runtime if certain keyword arguments are passed together. For example,
+/// `dataclasses.field` accepts either `default` or `default_factory`, but
+/// not both.
+///
+/// ## Example
+/// ```python
+/// import dataclasses
+///
+/// dataclasses.field(default=0, default_factory=int)
+/// ```
+///
+/// Use instead:
+/// ```python
+/// import dataclasses
+///
+/// dataclasses.field(default_factory=int)
+/// ```
+#[derive(ViolationMetadata)]
+pub(crate) struct MutuallyExclusiveKeywordArguments {
+    first: String,
+    second: String,
+}
+
+impl Violation for MutuallyExclusiveKeywordArguments {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let MutuallyExclusiveKeywordArguments { first, second } = self;
+        format!("`{first}` and `{second}` are mutually exclusive and cannot be used together")
+    }
+}
+
+/// Known pairs of mutually exclusive keyword arguments, keyed by the
+/// fully-qualified name of the callable that rejects their combination.
+const MUTUALLY_EXCLUSIVE_KEYWORDS: &[(&[&str], &str, &str)] = &[
+    (&["dataclasses", "field"], "default", "default_factory"),
+    (&["subprocess", "run"], "capture_output", "stdout"),
+    (&["subprocess", "run"], "capture_output", "stderr"),
+];
+
+/// B040
+pub(crate) fn mutually_exclusive_keyword_arguments(checker: &Checker, call: &ast::ExprCall) {
+    let Some(qualified_name) = checker.semantic().resolve_qualified_name(&call.func) else {
+        return;
+    };
+
+    for (target, first, second) in MUTUALLY_EXCLUSIVE_KEYWORDS {
+        if qualified_name.segments() != *target {
+            continue;
+        }
+        let Some(first_keyword) = call.arguments.find_keyword(first) else {
+            continue;
+        };
+        let Some(second_keyword) = call.arguments.find_keyword(second) else {
+            continue;
+        };
+        checker.report_diagnostic(Diagnostic::new(
+            MutuallyExclusiveKeywordArguments {
+                first: (*first).to_string(),
+                second: (*second).to_string(),
+            },
+            second_keyword.range().cover(first_keyword.range()),
+        ));
+    }
+}
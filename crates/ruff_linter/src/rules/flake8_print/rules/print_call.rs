@@ -1,6 +1,6 @@
 use ruff_diagnostics::{Diagnostic, Fix, FixAvailability, Violation};
 use ruff_macros::{derive_message_formats, ViolationMetadata};
-use ruff_python_ast as ast;
+use ruff_python_ast::{self as ast, Expr, Stmt};
 use ruff_text_size::Ranged;
 
 use crate::checkers::ast::Checker;
@@ -29,6 +29,10 @@ use crate::registry::AsRule;
 ///     return a + b
 /// ```
 ///
+/// `print` calls made directly within an `if __name__ == "__main__":` block
+/// are exempt, since such blocks typically guard a script's command-line
+/// entry point rather than library code.
+///
 /// ## Fix safety
 /// This rule's fix is marked as unsafe, as it may remove `print` statements
 /// that are used beyond debugging purposes.
@@ -118,6 +122,12 @@ pub(crate) fn print_call(checker: &Checker, call: &ast::ExprCall) {
                     }
                 }
             }
+            if semantic
+                .current_statements()
+                .any(|stmt| matches!(stmt, Stmt::If(stmt_if) if is_main_check(&stmt_if.test)))
+            {
+                return;
+            }
             Diagnostic::new(Print, call.func.range())
         }
         ["pprint", "pprint"] => Diagnostic::new(PPrint, call.func.range()),
@@ -141,3 +151,23 @@ pub(crate) fn print_call(checker: &Checker, call: &ast::ExprCall) {
 
     checker.report_diagnostic(diagnostic);
 }
+
+/// Returns `true` if an expression is an `if __name__ == "__main__":` check.
+fn is_main_check(expr: &Expr) -> bool {
+    let Expr::Compare(ast::ExprCompare {
+        left, comparators, ..
+    }) = expr
+    else {
+        return false;
+    };
+    let Expr::Name(ast::ExprName { id, .. }) = left.as_ref() else {
+        return false;
+    };
+    if id != "__name__" {
+        return false;
+    }
+    let [Expr::StringLiteral(ast::ExprStringLiteral { value, .. })] = &**comparators else {
+        return false;
+    };
+    value == "__main__"
+}
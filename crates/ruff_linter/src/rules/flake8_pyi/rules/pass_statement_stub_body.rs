@@ -22,6 +22,14 @@ use crate::checkers::ast::Checker;
 /// def foo(bar: int) -> list[int]: ...
 /// ```
 ///
+/// This rule only fires in `.pyi` files. It does not flag the opposite
+/// pattern &mdash; a bare `...` used as a placeholder function body in
+/// regular (non-stub) code &mdash; since that's a common, legitimate
+/// convention for `@overload` signatures, `Protocol` members, and abstract
+/// methods, and reliably distinguishing those cases from a genuinely
+/// unimplemented function would require type information this rule doesn't
+/// have.
+///
 /// ## References
 /// - [Typing documentation - Writing and Maintaining Stub Files](https://typing.python.org/en/latest/guides/writing_stubs.html)
 #[derive(ViolationMetadata)]
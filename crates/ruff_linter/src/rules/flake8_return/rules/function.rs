@@ -211,6 +211,10 @@ impl AlwaysFixableViolation for UnnecessaryAssign {
 ///         return 1
 ///     return baz
 /// ```
+///
+/// In a chain of `if`/`elif`/.../`else` branches, only the branch immediately
+/// following a terminating `if` or `elif` body is reported; if that branch is
+/// itself unwrapped, any subsequent `elif` in the chain is checked separately.
 #[derive(ViolationMetadata)]
 pub(crate) struct SuperfluousElseReturn {
     branch: Branch,
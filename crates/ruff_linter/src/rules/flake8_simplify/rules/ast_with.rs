@@ -166,6 +166,8 @@ pub(crate) fn multiple_with_statements(
             MultipleWithStatements,
             TextRange::new(with_stmt.start(), colon.end()),
         );
+        // `with` statements, unlike `try`, `for`, and `while`, have no `else` clause, so the
+        // only thing that can separate the outer and inner `with` is a comment.
         if !checker
             .comment_ranges()
             .intersects(TextRange::new(with_stmt.start(), with_stmt.body[0].start()))
@@ -18,6 +18,16 @@ use crate::Locator;
 
 /// Returns `true` if the [`ResolvedReference`] is in a typing-only context _or_ a runtime-evaluated
 /// context (with quoting enabled).
+///
+/// Combined with `Binding::references().all(...)`, this is how we determine whether *every*
+/// reference to a binding occurs in a typing context (see [`typing_only_runtime_import`]) versus
+/// whether *any* reference requires runtime evaluation (see [`is_valid_runtime_import`]), which is
+/// what lets us avoid suggesting a move into `TYPE_CHECKING` for imports that are also used at
+/// runtime. This lives here, rather than as a general-purpose method on `SemanticModel`, because
+/// what counts as "typing-only" depends on this plugin's settings (e.g. `quote_annotations`), which
+/// `ruff_python_semantic` has no knowledge of.
+///
+/// [`typing_only_runtime_import`]: crate::rules::flake8_type_checking::rules::typing_only_runtime_import
 pub(crate) fn is_typing_reference(reference: &ResolvedReference, settings: &Settings) -> bool {
     reference.in_type_checking_block()
         // if we're not in a type checking block, we necessarily need to be within a
@@ -128,6 +138,31 @@ fn runtime_required_decorators(
     })
 }
 
+/// Returns `true` if `expr` is a call to one of the configured
+/// `runtime_required_annotated_metadata` qualified names, as used in the
+/// metadata position of a PEP 593 `Annotated[...]` subscript.
+pub(crate) fn is_runtime_required_annotated_metadata(
+    expr: &Expr,
+    metadata: &[String],
+    semantic: &SemanticModel,
+) -> bool {
+    if metadata.is_empty() {
+        return false;
+    }
+
+    let Expr::Call(ast::ExprCall { func, .. }) = expr else {
+        return false;
+    };
+
+    semantic
+        .resolve_qualified_name(func)
+        .is_some_and(|qualified_name| {
+            metadata
+                .iter()
+                .any(|name| QualifiedName::from_dotted_name(name) == qualified_name)
+        })
+}
+
 /// Returns `true` if an annotation will be inspected at runtime by the `dataclasses` module.
 ///
 /// Specifically, detects whether an annotation is to either `dataclasses.InitVar` or
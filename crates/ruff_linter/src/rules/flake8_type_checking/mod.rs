@@ -257,6 +257,26 @@ mod tests {
         Ok(())
     }
 
+    #[test_case(
+        Rule::RuntimeImportInTypeCheckingBlock,
+        Path::new("runtime_required_annotated_metadata.py")
+    )]
+    fn runtime_required_annotated_metadata(rule_code: Rule, path: &Path) -> Result<()> {
+        let snapshot = format!("{}_{}", rule_code.as_ref(), path.to_string_lossy());
+        let diagnostics = test_path(
+            Path::new("flake8_type_checking").join(path).as_path(),
+            &settings::LinterSettings {
+                flake8_type_checking: super::settings::Settings {
+                    runtime_required_annotated_metadata: vec!["fastapi.Depends".to_string()],
+                    ..Default::default()
+                },
+                ..settings::LinterSettings::for_rule(rule_code)
+            },
+        )?;
+        assert_messages!(snapshot, diagnostics);
+        Ok(())
+    }
+
     #[test_case(Rule::TypingOnlyStandardLibraryImport, Path::new("module/direct.py"))]
     #[test_case(Rule::TypingOnlyStandardLibraryImport, Path::new("module/import.py"))]
     #[test_case(
@@ -10,6 +10,7 @@ pub struct Settings {
     pub exempt_modules: Vec<String>,
     pub runtime_required_base_classes: Vec<String>,
     pub runtime_required_decorators: Vec<String>,
+    pub runtime_required_annotated_metadata: Vec<String>,
     pub quote_annotations: bool,
 }
 
@@ -20,6 +21,7 @@ impl Default for Settings {
             exempt_modules: vec!["typing".to_string(), "typing_extensions".to_string()],
             runtime_required_base_classes: vec![],
             runtime_required_decorators: vec![],
+            runtime_required_annotated_metadata: vec![],
             quote_annotations: false,
         }
     }
@@ -35,6 +37,7 @@ impl Display for Settings {
                 self.exempt_modules | array,
                 self.runtime_required_base_classes | array,
                 self.runtime_required_decorators | array,
+                self.runtime_required_annotated_metadata | array,
                 self.quote_annotations
             ]
         }
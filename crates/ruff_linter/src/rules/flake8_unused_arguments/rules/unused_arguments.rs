@@ -36,6 +36,12 @@ use crate::registry::Rule;
 ///
 /// ## Options
 /// - `lint.dummy-variable-rgx`
+///
+/// ## See also
+/// This rule flags unused *parameters*. For unused *local variables*, see
+/// [`unused-variable`][F841].
+///
+/// [F841]: https://docs.astral.sh/ruff/rules/unused-variable/
 #[derive(ViolationMetadata)]
 pub(crate) struct UnusedFunctionArgument {
     name: String,
@@ -855,6 +855,12 @@ impl Violation for OsPathIsabs {
 /// it can be less performant than the lower-level alternatives that work directly with strings,
 /// especially on older versions of Python.
 ///
+/// This rule doesn't provide a fix, since the arguments to `os.path.join()` and
+/// `os.sep.join()` aren't guaranteed to be `Path` objects, or even strings that
+/// represent path segments; rewriting them all to `Path(...) / ...` or
+/// `Path(...).joinpath(...)` requires knowing that each argument is safe to
+/// join with, which the rule can't verify.
+///
 /// ## References
 /// - [Python documentation: `PurePath.joinpath`](https://docs.python.org/3/library/pathlib.html#pathlib.PurePath.joinpath)
 /// - [Python documentation: `os.path.join`](https://docs.python.org/3/library/os.path.html#os.path.join)
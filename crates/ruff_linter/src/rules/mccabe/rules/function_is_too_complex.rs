@@ -177,10 +177,10 @@ pub(crate) fn function_is_too_complex(
 mod tests {
     use anyhow::Result;
 
-    use ruff_python_ast::Suite;
+    use ruff_python_ast::{Stmt, Suite};
     use ruff_python_parser::parse_module;
 
-    use super::get_complexity_number;
+    use super::{function_is_too_complex, get_complexity_number};
 
     fn parse_suite(source: &str) -> Result<Suite> {
         Ok(parse_module(source)?.into_suite())
@@ -311,6 +311,35 @@ def nested_functions():
         Ok(())
     }
 
+    #[test]
+    fn nested_functions_are_checked_independently() -> Result<()> {
+        // A nested function's complexity is folded into its parent's count (mirroring
+        // the parent's own control-flow graph, which includes the `def`), but the
+        // checker also calls `function_is_too_complex` once per `Stmt::FunctionDef`,
+        // including nested ones. So a deeply-nested function is still caught on its
+        // own terms, even if its parent's complexity is well under the threshold.
+        let source = r"
+def outer():
+    def inner(n):
+        if n > 0:
+            if n > 1:
+                if n > 2:
+                    return 1
+        return 0
+";
+        let stmts = parse_suite(source)?;
+        let Stmt::FunctionDef(outer) = &stmts[0] else {
+            panic!("expected a function definition");
+        };
+        let Stmt::FunctionDef(inner) = &outer.body[0] else {
+            panic!("expected a function definition");
+        };
+
+        assert!(function_is_too_complex(&stmts[0], "outer", &outer.body, 10).is_none());
+        assert!(function_is_too_complex(&outer.body[0], "inner", &inner.body, 2).is_some());
+        Ok(())
+    }
+
     #[test]
     fn try_else() -> Result<()> {
         let source = r"
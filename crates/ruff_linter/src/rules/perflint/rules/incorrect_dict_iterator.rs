@@ -10,8 +10,8 @@ use crate::checkers::ast::Checker;
 use crate::fix::edits::pad;
 
 /// ## What it does
-/// Checks for uses of `dict.items()` that discard either the key or the value
-/// when iterating over the dictionary.
+/// Checks for uses of `dict.items()` that discard the key, the value, or
+/// both, when iterating over the dictionary.
 ///
 /// ## Why is this bad?
 /// If you only need the keys or values of a dictionary, you should use
@@ -20,6 +20,9 @@ use crate::fix::edits::pad;
 /// avoid allocating tuples for every item in the dictionary. They also
 /// communicate the intent of the code more clearly.
 ///
+/// If neither the key nor the value is used, iterate over the dictionary
+/// directly, which is equivalent to iterating over its keys.
+///
 /// Note that, as with all `perflint` rules, this is only intended as a
 /// micro-optimization, and will have a negligible impact on performance in
 /// most cases.
@@ -52,12 +55,28 @@ impl AlwaysFixableViolation for IncorrectDictIterator {
     #[derive_message_formats]
     fn message(&self) -> String {
         let IncorrectDictIterator { subset } = self;
-        format!("When using only the {subset} of a dict use the `{subset}()` method")
+        match subset {
+            DictSubset::Neither => {
+                "When using neither the key nor the value of a dict, iterate over the dict \
+                 directly"
+                    .to_string()
+            }
+            DictSubset::Keys | DictSubset::Values => {
+                format!("When using only the {subset} of a dict use the `{subset}()` method")
+            }
+        }
     }
 
     fn fix_title(&self) -> String {
         let IncorrectDictIterator { subset } = self;
-        format!("Replace `.items()` with `.{subset}()`")
+        match subset {
+            DictSubset::Neither => {
+                "Replace `.items()` call with iteration over the dict".to_string()
+            }
+            DictSubset::Keys | DictSubset::Values => {
+                format!("Replace `.items()` with `.{subset}()`")
+            }
+        }
     }
 }
 
@@ -80,7 +99,10 @@ pub(crate) fn incorrect_dict_iterator(checker: &Checker, stmt_for: &ast::StmtFor
     if !args.is_empty() {
         return;
     }
-    let Expr::Attribute(ast::ExprAttribute { attr, .. }) = func.as_ref() else {
+    let Expr::Attribute(ast::ExprAttribute {
+        attr, value: obj, ..
+    }) = func.as_ref()
+    else {
         return;
     };
     if attr != "items" {
@@ -92,7 +114,21 @@ pub(crate) fn incorrect_dict_iterator(checker: &Checker, stmt_for: &ast::StmtFor
         checker.semantic().is_unused(value),
     ) {
         (true, true) => {
-            // Both the key and the value are unused.
+            // Both the key and the value are unused, so iterate over the dict directly.
+            let mut diagnostic = Diagnostic::new(
+                IncorrectDictIterator {
+                    subset: DictSubset::Neither,
+                },
+                func.range(),
+            );
+            let replace_iterator = Edit::range_replacement(
+                checker.locator().slice(obj.as_ref()).to_string(),
+                stmt_for.iter.range(),
+            );
+            let replace_target =
+                Edit::range_replacement("_".to_string(), stmt_for.target.range());
+            diagnostic.set_fix(Fix::unsafe_edits(replace_iterator, [replace_target]));
+            checker.report_diagnostic(diagnostic);
         }
         (false, false) => {
             // Neither the key nor the value are unused.
@@ -144,6 +180,7 @@ pub(crate) fn incorrect_dict_iterator(checker: &Checker, stmt_for: &ast::StmtFor
 enum DictSubset {
     Keys,
     Values,
+    Neither,
 }
 
 impl fmt::Display for DictSubset {
@@ -151,6 +188,7 @@ impl fmt::Display for DictSubset {
         match self {
             DictSubset::Keys => fmt.write_str("keys"),
             DictSubset::Values => fmt.write_str("values"),
+            DictSubset::Neither => fmt.write_str("neither"),
         }
     }
 }
@@ -15,6 +15,16 @@ use crate::Locator;
 /// ## Why is this bad?
 /// Invalid escape sequences are deprecated in Python 3.6.
 ///
+/// This commonly comes up in regular expressions passed to `re.compile` and
+/// similar functions: a pattern like `re.compile("\d+")` contains `\d`,
+/// which isn't a valid Python string escape, so this rule flags it and
+/// suggests `re.compile(r"\d+")`. However, this rule only fires when the
+/// string contains an *invalid* escape; a pattern written with doubled
+/// backslashes, such as `re.compile("\\d+")`, is valid Python and isn't
+/// flagged here. `RUF039` covers that case instead, flagging any non-raw
+/// string literal passed to `re.compile` and similar functions regardless
+/// of whether its escapes happen to be valid.
+///
 /// ## Example
 /// ```python
 /// regex = "\.png$"
@@ -55,6 +55,10 @@ impl EqCmpOp {
 /// In these cases, `is`/`is not` may not be equivalent to `==`/`!=`. For more
 /// information, see [this issue].
 ///
+/// When `None` appears in a chained comparison (e.g., `a == None == b`), the fix
+/// rewrites the entire chain rather than skipping it, so that only the offending
+/// operator changes and the comparison keeps evaluating the same operands.
+///
 /// [PEP 8]: https://peps.python.org/pep-0008/#programming-recommendations
 /// [this issue]: https://github.com/astral-sh/ruff/issues/4560
 #[derive(ViolationMetadata)]
@@ -117,6 +121,10 @@ impl AlwaysFixableViolation for NoneComparison {
 /// In these cases, `is`/`is not` may not be equivalent to `==`/`!=`. For more
 /// information, see [this issue].
 ///
+/// When `True` or `False` appears in a chained comparison (e.g., `a == True != b`),
+/// the fix rewrites the entire chain rather than skipping it, so that only the
+/// offending operator changes and the comparison keeps evaluating the same operands.
+///
 /// [PEP 8]: https://peps.python.org/pep-0008/#programming-recommendations
 /// [this issue]: https://github.com/astral-sh/ruff/issues/4560
 #[derive(ViolationMetadata)]
@@ -48,6 +48,10 @@ use crate::checkers::ast::Checker;
 /// if isinstance(obj, int):
 ///     pass
 /// ```
+///
+/// This rule has no autofix, since `type(obj) == int` is a strict type check, while
+/// `isinstance(obj, int)` also accepts subclasses of `int`; rewriting one as the other could
+/// silently change the behavior of the code.
 #[derive(ViolationMetadata)]
 pub(crate) struct TypeComparison;
 
@@ -174,7 +174,7 @@ pub(crate) fn blank_before_after_class(checker: &Checker, docstring: &Docstring)
         || checker.enabled(Rule::BlankLineBeforeClass)
     {
         let mut lines = UniversalNewlineIterator::with_offset(
-            checker.locator().slice(between_range),
+            checker.source_slice(between_range),
             between_range.start(),
         )
         .rev();
@@ -219,7 +219,7 @@ pub(crate) fn blank_before_after_class(checker: &Checker, docstring: &Docstring)
 
     if checker.enabled(Rule::IncorrectBlankLineAfterClass) {
         let class_after_docstring_range = TextRange::new(docstring.end(), class.end());
-        let class_after_docstring = checker.locator().slice(class_after_docstring_range);
+        let class_after_docstring = checker.source_slice(class_after_docstring_range);
         let mut lines = UniversalNewlineIterator::with_offset(
             class_after_docstring,
             class_after_docstring_range.start(),
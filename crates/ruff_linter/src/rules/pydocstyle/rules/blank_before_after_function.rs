@@ -108,9 +108,7 @@ pub(crate) fn blank_before_after_function(checker: &Checker, docstring: &Docstri
     };
 
     if checker.enabled(Rule::BlankLineBeforeFunction) {
-        let before = checker
-            .locator()
-            .slice(TextRange::new(function.start(), docstring.start()));
+        let before = checker.source_slice(TextRange::new(function.start(), docstring.start()));
 
         let mut lines = UniversalNewlineIterator::with_offset(before, function.start()).rev();
         let mut blank_lines_before = 0usize;
@@ -142,9 +140,7 @@ pub(crate) fn blank_before_after_function(checker: &Checker, docstring: &Docstri
     }
 
     if checker.enabled(Rule::BlankLineAfterFunction) {
-        let after = checker
-            .locator()
-            .slice(TextRange::new(docstring.end(), function.end()));
+        let after = checker.source_slice(TextRange::new(docstring.end(), function.end()));
 
         // If the docstring is only followed by blank and commented lines, abort.
         let all_blank_after = after.universal_newlines().skip(1).all(|line| {
@@ -1,17 +1,21 @@
+use std::fmt;
+
 use ruff_diagnostics::Violation;
 use ruff_macros::{derive_message_formats, ViolationMetadata};
 use ruff_source_file::SourceRow;
 
 /// ## What it does
-/// Checks for import bindings that are shadowed by loop variables.
+/// Checks for import bindings that are shadowed by loop variables or by
+/// `with` statement targets.
 ///
 /// ## Why is this bad?
-/// Shadowing an import with loop variables makes the code harder to read and
-/// reason about, as the identify of the imported binding is no longer clear.
-/// It's also often indicative of a mistake, as it's unlikely that the loop
-/// variable is intended to be used as the imported binding.
+/// Shadowing an import with a loop variable or a `with` statement target
+/// makes the code harder to read and reason about, as the identify of the
+/// imported binding is no longer clear. It's also often indicative of a
+/// mistake, as it's unlikely that the loop variable or `with` target is
+/// intended to be used as the imported binding.
 ///
-/// Consider using a different name for the loop variable.
+/// Consider using a different name for the loop variable or `with` target.
 ///
 /// ## Example
 /// ```python
@@ -33,13 +37,33 @@ use ruff_source_file::SourceRow;
 pub(crate) struct ImportShadowedByLoopVar {
     pub(crate) name: String,
     pub(crate) row: SourceRow,
+    pub(crate) shadowing_kind: ImportShadowingKind,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum ImportShadowingKind {
+    LoopVar,
+    WithItemVar,
+}
+
+impl fmt::Display for ImportShadowingKind {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ImportShadowingKind::LoopVar => fmt.write_str("loop variable"),
+            ImportShadowingKind::WithItemVar => fmt.write_str("`with` statement variable"),
+        }
+    }
 }
 
 impl Violation for ImportShadowedByLoopVar {
     #[derive_message_formats]
     fn message(&self) -> String {
-        let ImportShadowedByLoopVar { name, row } = self;
-        format!("Import `{name}` from {row} shadowed by loop variable")
+        let ImportShadowedByLoopVar {
+            name,
+            row,
+            shadowing_kind,
+        } = self;
+        format!("Import `{name}` from {row} shadowed by {shadowing_kind}")
     }
 }
 
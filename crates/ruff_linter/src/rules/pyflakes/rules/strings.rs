@@ -450,8 +450,10 @@ impl Violation for StringDotFormatExtraPositionalArguments {
 /// Checks for `str.format` calls with placeholders that are missing arguments.
 ///
 /// ## Why is this bad?
-/// In `str.format` calls, omitting arguments for placeholders will raise a
-/// `KeyError` at runtime.
+/// In `str.format` calls, omitting arguments for placeholders will raise an
+/// error at runtime: an `IndexError` for a missing positional or
+/// automatically-numbered placeholder, or a `KeyError` for a missing named
+/// placeholder.
 ///
 /// ## Example
 /// ```python
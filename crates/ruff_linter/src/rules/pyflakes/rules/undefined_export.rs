@@ -18,6 +18,10 @@ use ruff_macros::{derive_message_formats, ViolationMetadata};
 /// that rely on implicit exports should disable this rule in `__init__.py`
 /// files via [`lint.per-file-ignores`].
 ///
+/// This also flags an `__all__` that references nothing, e.g., an
+/// `__init__.py` with `__all__ = ["missing"]` but no corresponding
+/// definition or import.
+///
 /// ## Example
 /// ```python
 /// from foo import bar
@@ -255,6 +255,28 @@ fn is_first_party(import: &AnyImport, checker: &Checker) -> bool {
     }
 }
 
+/// Returns `true` if `name` appears as a bare word in a `>>>`- or `...`-prefixed doctest line
+/// within the module's docstring.
+fn is_referenced_in_module_doctest(checker: &Checker, name: &str) -> bool {
+    let Some(body) = checker.semantic().definitions.python_ast() else {
+        return false;
+    };
+    let Some(docstring) = crate::docstrings::extraction::docstring_from(body) else {
+        return false;
+    };
+    docstring.value.to_str().lines().any(|line| {
+        let trimmed = line.trim_start();
+        let Some(code) = trimmed
+            .strip_prefix(">>> ")
+            .or_else(|| trimmed.strip_prefix("... "))
+        else {
+            return false;
+        };
+        code.split(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .any(|word| word == name)
+    })
+}
+
 /// Find the `Expr` for top level `__all__` bindings.
 fn find_dunder_all_exprs<'a>(semantic: &'a SemanticModel) -> Vec<&'a ast::Expr> {
     semantic
@@ -340,6 +362,14 @@ pub(crate) fn unused_import(checker: &Checker, scope: &Scope) {
             continue;
         }
 
+        // If the import is only referenced from a doctest in the module's docstring, and the
+        // user has opted in to treating that as a use, avoid treating it as unused.
+        if checker.settings.pyflakes.allow_unused_imports_in_doctests
+            && is_referenced_in_module_doctest(checker, name)
+        {
+            continue;
+        }
+
         let import = ImportBinding {
             name,
             import,
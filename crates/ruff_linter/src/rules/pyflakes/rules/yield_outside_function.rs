@@ -44,6 +44,16 @@ impl From<YieldOutsideFunctionKind> for DeferralKeyword {
 ///     yield 1
 /// ```
 ///
+/// This also applies to `yield`, `yield from`, and `await` used inside of a
+/// comprehension or generator expression, even one nested inside a function:
+/// the comprehension introduces its own scope, and that scope is never a
+/// function, so a `yield` there is unconditionally invalid, as of Python 3.8.
+///
+/// ```python
+/// def f():
+///     return [(yield x) for x in range(3)]
+/// ```
+///
 /// ## Notebook behavior
 /// As an exception, `await` is allowed at the top level of a Jupyter notebook
 /// (see: [autoawait]).
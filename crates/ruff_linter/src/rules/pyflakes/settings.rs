@@ -8,6 +8,7 @@ use std::fmt;
 pub struct Settings {
     pub extend_generics: Vec<String>,
     pub allowed_unused_imports: Vec<String>,
+    pub allow_unused_imports_in_doctests: bool,
 }
 
 impl fmt::Display for Settings {
@@ -17,7 +18,8 @@ impl fmt::Display for Settings {
             namespace = "linter.pyflakes",
             fields = [
                 self.extend_generics | debug,
-                self.allowed_unused_imports | debug
+                self.allowed_unused_imports | debug,
+                self.allow_unused_imports_in_doctests
             ]
         }
         Ok(())
@@ -32,6 +32,11 @@ use crate::Locator;
 /// from foo import secrets  # type: ignore[attr-defined]
 /// ```
 ///
+/// This rule is not enabled by default, as it's only useful for codebases
+/// that rely on mypy (or another type checker that recognizes `type: ignore`
+/// comments) and want to enforce specific error codes; select `PGH003`
+/// explicitly to opt in.
+///
 /// ## References
 /// Mypy supports a [built-in setting](https://mypy.readthedocs.io/en/stable/error_code_list2.html#check-that-type-ignore-include-an-error-code-ignore-without-code)
 /// to enforce that all `type: ignore` annotations include an error code, akin
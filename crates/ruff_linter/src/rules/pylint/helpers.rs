@@ -18,7 +18,7 @@ pub(super) fn type_param_name(arguments: &Arguments) -> Option<&str> {
     }
 }
 
-pub(super) fn in_dunder_method(
+pub(crate) fn in_dunder_method(
     dunder_name: &str,
     semantic: &SemanticModel,
     settings: &LinterSettings,
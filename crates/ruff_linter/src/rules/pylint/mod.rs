@@ -29,6 +29,7 @@ mod tests {
     #[test_case(Rule::AssertOnStringLiteral, Path::new("assert_on_string_literal.py"))]
     #[test_case(Rule::AwaitOutsideAsync, Path::new("await_outside_async.py"))]
     #[test_case(Rule::AwaitOutsideAsync, Path::new("await_outside_async.ipynb"))]
+    #[test_case(Rule::BadExceptOrder, Path::new("bad_except_order.py"))]
     #[test_case(Rule::BadOpenMode, Path::new("bad_open_mode.py"))]
     #[test_case(
         Rule::BadStringFormatCharacter,
@@ -45,6 +46,10 @@ mod tests {
     #[test_case(Rule::CollapsibleElseIf, Path::new("collapsible_else_if.py"))]
     #[test_case(Rule::CompareToEmptyString, Path::new("compare_to_empty_string.py"))]
     #[test_case(Rule::ComparisonOfConstant, Path::new("comparison_of_constant.py"))]
+    #[test_case(
+        Rule::TautologicalChainedComparison,
+        Path::new("tautological_chained_comparison.py")
+    )]
     #[test_case(Rule::ComparisonWithItself, Path::new("comparison_with_itself.py"))]
     #[test_case(Rule::EqWithoutHash, Path::new("eq_without_hash.py"))]
     #[test_case(Rule::EmptyComment, Path::new("empty_comment.py"))]
@@ -80,6 +85,10 @@ mod tests {
         Path::new("import_private_name/submodule/__main__.py")
     )]
     #[test_case(Rule::ImportSelf, Path::new("import_self/module.py"))]
+    #[test_case(
+        Rule::InconsistentReturnStatements,
+        Path::new("inconsistent_return_statements.py")
+    )]
     #[test_case(Rule::InvalidAllFormat, Path::new("invalid_all_format.py"))]
     #[test_case(Rule::InvalidAllObject, Path::new("invalid_all_object.py"))]
     #[test_case(Rule::InvalidBoolReturnType, Path::new("invalid_return_type_bool.py"))]
@@ -96,6 +105,7 @@ mod tests {
         Rule::InvalidLengthReturnType,
         Path::new("invalid_return_type_length.py")
     )]
+    #[test_case(Rule::InvalidReprReturnType, Path::new("invalid_return_type_repr.py"))]
     #[test_case(Rule::InvalidStrReturnType, Path::new("invalid_return_type_str.py"))]
     #[test_case(Rule::DuplicateBases, Path::new("duplicate_bases.py"))]
     #[test_case(Rule::InvalidCharacterBackspace, Path::new("invalid_characters.py"))]
@@ -117,6 +127,7 @@ mod tests {
     #[test_case(Rule::LoggingTooFewArgs, Path::new("logging_too_few_args.py"))]
     #[test_case(Rule::LoggingTooManyArgs, Path::new("logging_too_many_args.py"))]
     #[test_case(Rule::MagicValueComparison, Path::new("magic_value_comparison.py"))]
+    #[test_case(Rule::MissingSuperCall, Path::new("missing_super_call.py"))]
     #[test_case(Rule::ModifiedIteratingSet, Path::new("modified_iterating_set.py"))]
     #[test_case(
         Rule::NamedExprWithoutContext,
@@ -417,6 +428,22 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn too_many_global_statements() -> Result<()> {
+        let diagnostics = test_path(
+            Path::new("pylint/too_many_global_statements.py"),
+            &LinterSettings {
+                pylint: pylint::settings::Settings {
+                    max_globals: 2,
+                    ..pylint::settings::Settings::default()
+                },
+                ..LinterSettings::for_rules(vec![Rule::TooManyGlobalStatements])
+            },
+        )?;
+        assert_messages!(diagnostics);
+        Ok(())
+    }
+
     #[test]
     fn import_outside_top_level_with_banned() -> Result<()> {
         let diagnostics = test_path(
@@ -0,0 +1,151 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::{self as ast, ExceptHandler, Expr};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `except` clauses that are ordered such that a broad exception
+/// is caught before a narrower exception that it already covers.
+///
+/// ## Why is this bad?
+/// When an earlier `except` clause catches a superclass of the exception
+/// caught by a later `except` clause, the later clause can never be reached:
+/// the earlier, broader handler will always catch the exception first.
+///
+/// ## Example
+/// ```python
+/// try:
+///     ...
+/// except Exception:
+///     ...
+/// except ValueError:  # Unreachable, since `Exception` was already caught above.
+///     ...
+/// ```
+///
+/// Use instead:
+/// ```python
+/// try:
+///     ...
+/// except ValueError:
+///     ...
+/// except Exception:
+///     ...
+/// ```
+///
+/// ## Known problems
+/// This rule is limited to exceptions in the builtin exception hierarchy
+/// that can be resolved via static analysis; it does not reason about
+/// user-defined exception hierarchies.
+///
+/// ## References
+/// - [Python documentation: Exception hierarchy](https://docs.python.org/3/library/exceptions.html#exception-hierarchy)
+#[derive(ViolationMetadata)]
+pub(crate) struct BadExceptOrder {
+    superclass: String,
+    subclass: String,
+}
+
+impl Violation for BadExceptOrder {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let BadExceptOrder {
+            superclass,
+            subclass,
+        } = self;
+        format!(
+            "`except {subclass}` is unreachable because `except {superclass}` is already caught above"
+        )
+    }
+}
+
+/// Return the direct builtin superclass of `name`, if `name` is a builtin
+/// exception with a known parent in the exception hierarchy.
+fn builtin_exception_parent(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "GeneratorExit" | "KeyboardInterrupt" | "SystemExit" | "Exception" => "BaseException",
+        "ArithmeticError" | "AssertionError" | "AttributeError" | "BufferError" | "EOFError"
+        | "ImportError" | "LookupError" | "MemoryError" | "NameError" | "OSError"
+        | "ReferenceError" | "RuntimeError" | "StopIteration" | "StopAsyncIteration"
+        | "SyntaxError" | "SystemError" | "TypeError" | "ValueError" | "Warning" => "Exception",
+        "FloatingPointError" | "OverflowError" | "ZeroDivisionError" => "ArithmeticError",
+        "ModuleNotFoundError" => "ImportError",
+        "IndexError" | "KeyError" => "LookupError",
+        "UnboundLocalError" => "NameError",
+        "BlockingIOError" | "ChildProcessError" | "ConnectionError" | "FileExistsError"
+        | "FileNotFoundError" | "InterruptedError" | "IsADirectoryError" | "NotADirectoryError"
+        | "PermissionError" | "ProcessLookupError" | "TimeoutError" => "OSError",
+        "IOError" | "EnvironmentError" => "OSError",
+        "BrokenPipeError" | "ConnectionAbortedError" | "ConnectionRefusedError"
+        | "ConnectionResetError" => "ConnectionError",
+        "NotImplementedError" | "RecursionError" => "RuntimeError",
+        "IndentationError" => "SyntaxError",
+        "TabError" => "IndentationError",
+        "UnicodeError" => "ValueError",
+        "UnicodeDecodeError" | "UnicodeEncodeError" | "UnicodeTranslateError" => "UnicodeError",
+        _ => return None,
+    })
+}
+
+/// Return `true` if `name` is a known builtin exception, i.e., is either
+/// `BaseException` or has a known ancestor in the builtin hierarchy.
+fn is_known_builtin_exception(name: &str) -> bool {
+    name == "BaseException" || builtin_exception_parent(name).is_some()
+}
+
+/// Return `true` if `superclass` is a strict ancestor of `subclass` in the
+/// builtin exception hierarchy.
+fn is_builtin_exception_ancestor(superclass: &str, subclass: &str) -> bool {
+    let mut current = subclass;
+    while let Some(parent) = builtin_exception_parent(current) {
+        if parent == superclass {
+            return true;
+        }
+        current = parent;
+    }
+    false
+}
+
+/// PLW0705
+pub(crate) fn bad_except_order(checker: &Checker, handlers: &[ExceptHandler]) {
+    let mut seen: Vec<&str> = Vec::new();
+    for handler in handlers {
+        let ExceptHandler::ExceptHandler(ast::ExceptHandlerExceptHandler {
+            type_: Some(type_),
+            ..
+        }) = handler
+        else {
+            continue;
+        };
+
+        let candidates: Vec<&Expr> = match type_.as_ref() {
+            Expr::Tuple(ast::ExprTuple { elts, .. }) => elts.iter().collect(),
+            other => vec![other],
+        };
+
+        for candidate in candidates {
+            let Some(name) = checker.semantic().resolve_builtin_symbol(candidate) else {
+                continue;
+            };
+            if !is_known_builtin_exception(name) {
+                continue;
+            }
+
+            if let Some(&superclass) = seen
+                .iter()
+                .find(|&&seen_name| is_builtin_exception_ancestor(seen_name, name))
+            {
+                checker.report_diagnostic(Diagnostic::new(
+                    BadExceptOrder {
+                        superclass: superclass.to_string(),
+                        subclass: name.to_string(),
+                    },
+                    candidate.range(),
+                ));
+            }
+
+            seen.push(name);
+        }
+    }
+}
@@ -33,15 +33,22 @@ use crate::checkers::ast::Checker;
 #[derive(ViolationMetadata)]
 pub(crate) struct ComparisonWithItself {
     actual: SourceCodeSnippet,
+    is_ne: bool,
 }
 
 impl Violation for ComparisonWithItself {
     #[derive_message_formats]
     fn message(&self) -> String {
-        if let Some(actual) = self.actual.full_display() {
-            format!("Name compared with itself, consider replacing `{actual}`")
+        let Some(actual) = self.actual.full_display() else {
+            return "Name compared with itself".to_string();
+        };
+        if self.is_ne {
+            format!(
+                "Name compared with itself, consider replacing `{actual}`; if this is a NaN \
+                 check, use `math.isnan` instead"
+            )
         } else {
-            "Name compared with itself".to_string()
+            format!("Name compared with itself, consider replacing `{actual}`")
         }
     }
 }
@@ -70,6 +77,7 @@ pub(crate) fn comparison_with_itself(
                 checker.report_diagnostic(Diagnostic::new(
                     ComparisonWithItself {
                         actual: SourceCodeSnippet::new(actual),
+                        is_ne: matches!(op, CmpOp::NotEq),
                     },
                     left_name.range(),
                 ));
@@ -118,6 +126,7 @@ pub(crate) fn comparison_with_itself(
                     checker.report_diagnostic(Diagnostic::new(
                         ComparisonWithItself {
                             actual: SourceCodeSnippet::new(actual),
+                            is_ne: matches!(op, CmpOp::NotEq),
                         },
                         left_call.range(),
                     ));
@@ -1,6 +1,7 @@
 use ruff_diagnostics::{Diagnostic, Violation};
 use ruff_macros::{derive_message_formats, ViolationMetadata};
 use ruff_python_ast::Stmt;
+use ruff_python_semantic::Exceptions;
 use ruff_text_size::Ranged;
 
 use crate::rules::flake8_tidy_imports::rules::BannedModuleImportPolicies;
@@ -27,6 +28,11 @@ use crate::{
 /// avoid a circular dependency, to defer a costly module load, or to avoid
 /// loading a dependency altogether in a certain runtime environment.
 ///
+/// This rule exempts imports that are guarded by a `try`/`except` block that
+/// handles `ImportError` or `ModuleNotFoundError`, as well as imports guarded
+/// by `if TYPE_CHECKING:`, since both patterns are common, deliberate ways of
+/// making a dependency optional or type-checking-only.
+///
 /// ## Example
 /// ```python
 /// def print_python_version():
@@ -62,6 +68,20 @@ pub(crate) fn import_outside_top_level(checker: &Checker, stmt: &Stmt) {
         return;
     }
 
+    if checker.semantic().in_type_checking_block() {
+        // Imports guarded by `if TYPE_CHECKING:` are allowed
+        return;
+    }
+
+    if checker
+        .semantic()
+        .exceptions()
+        .intersects(Exceptions::MODULE_NOT_FOUND_ERROR | Exceptions::IMPORT_ERROR)
+    {
+        // Imports guarded by `try: ... except ImportError:` are allowed
+        return;
+    }
+
     // Check if any of the non-top-level imports are banned by TID253
     // before emitting the diagnostic to avoid conflicts.
     if checker.enabled(Rule::BannedModuleLevelImports) {
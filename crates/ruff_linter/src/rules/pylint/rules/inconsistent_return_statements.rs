@@ -0,0 +1,83 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::helpers::ReturnStatementVisitor;
+use ruff_python_ast::identifier::Identifier;
+use ruff_python_ast::visitor::Visitor;
+use ruff_python_ast::Stmt;
+
+/// ## What it does
+/// Checks for functions that mix `return` statements that return a value
+/// with bare `return` statements (or an implicit `return` at the end of the
+/// function).
+///
+/// ## Why is this bad?
+/// Mixing value-returning and value-less `return` statements in the same
+/// function is a common source of bugs: callers can't rely on the function
+/// consistently returning a meaningful value, and it's often unclear whether
+/// the missing value was intentional.
+///
+/// ## Example
+/// ```python
+/// def foo(x):
+///     if x > 0:
+///         return x
+///     return
+/// ```
+///
+/// Use instead:
+/// ```python
+/// def foo(x):
+///     if x > 0:
+///         return x
+///     return None
+/// ```
+///
+/// ## Known problems
+/// This rule only flags the clear case where a function contains at least
+/// one `return` statement with a value and at least one bare `return`
+/// statement. It does not perform full control-flow analysis, so it won't
+/// catch cases where a function implicitly falls off the end without an
+/// explicit bare `return`.
+///
+/// Generator functions are exempt: a bare `return` there is an early exit,
+/// while a `return <value>` sets the value attached to the `StopIteration`
+/// raised at the end of iteration (used by `yield from` consumers), so
+/// mixing the two is idiomatic rather than inconsistent.
+#[derive(ViolationMetadata)]
+pub(crate) struct InconsistentReturnStatements;
+
+impl Violation for InconsistentReturnStatements {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        "Either all return statements in a function should return an expression, or none of them should".to_string()
+    }
+}
+
+/// PLR1710
+pub(crate) fn inconsistent_return_statements(stmt: &Stmt, body: &[Stmt]) -> Option<Diagnostic> {
+    let mut visitor = ReturnStatementVisitor::default();
+    visitor.visit_body(body);
+
+    if visitor.is_generator {
+        return None;
+    }
+
+    let mut has_value = false;
+    let mut has_bare = false;
+    for return_stmt in &visitor.returns {
+        if return_stmt.value.is_some() {
+            has_value = true;
+        } else {
+            has_bare = true;
+        }
+    }
+
+    if has_value && has_bare {
+        Some(Diagnostic::new(
+            InconsistentReturnStatements,
+            stmt.identifier(),
+        ))
+    } else {
+        None
+    }
+}
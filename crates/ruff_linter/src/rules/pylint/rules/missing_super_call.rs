@@ -0,0 +1,159 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::{
+    self as ast,
+    visitor::{self, Visitor},
+    Expr, Stmt, StmtClassDef,
+};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `__init__` methods in subclasses that don't call
+/// `super().__init__()` (or an equivalent base-class `__init__`).
+///
+/// ## Why is this bad?
+/// When a subclass defines its own `__init__` method, the base class's
+/// `__init__` is no longer called automatically. If the base class relies
+/// on its `__init__` to set up required state, omitting the `super()` call
+/// leaves the object partially initialized, which typically manifests as
+/// an `AttributeError` later on.
+///
+/// ## Example
+/// ```python
+/// class Base:
+///     def __init__(self):
+///         self.value = 1
+///
+///
+/// class Derived(Base):
+///     def __init__(self):
+///         self.other = 2
+/// ```
+///
+/// Use instead:
+/// ```python
+/// class Base:
+///     def __init__(self):
+///         self.value = 1
+///
+///
+/// class Derived(Base):
+///     def __init__(self):
+///         super().__init__()
+///         self.other = 2
+/// ```
+///
+/// ## Known problems
+/// This rule doesn't attempt to distinguish mixins or other classes that
+/// intentionally skip base-class initialization from genuine bugs, so it's
+/// off by default.
+#[derive(ViolationMetadata)]
+pub(crate) struct MissingSuperCall;
+
+impl Violation for MissingSuperCall {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        "`__init__` method does not call `super().__init__()`".to_string()
+    }
+}
+
+/// PLW0231
+pub(crate) fn missing_super_call(checker: &Checker, class_def: &StmtClassDef) {
+    if checker.source_type.is_stub() {
+        return;
+    }
+
+    // A class without (non-`object`) bases has nothing to initialize.
+    let Some(arguments) = class_def.arguments.as_deref() else {
+        return;
+    };
+    let base_names: Vec<&str> = arguments
+        .args
+        .iter()
+        .filter_map(|base| match base {
+            Expr::Name(ast::ExprName { id, .. }) if id != "object" => Some(id.as_str()),
+            _ => None,
+        })
+        .collect();
+    if base_names.is_empty() {
+        return;
+    }
+
+    // Dataclasses generate their own `__init__`, so a hand-written one that skips
+    // `super().__init__()` is a deliberate override, not an oversight.
+    if class_def.decorator_list.iter().any(|decorator| {
+        checker
+            .semantic()
+            .resolve_qualified_name(&decorator.expression)
+            .is_some_and(|name| matches!(name.segments(), ["dataclasses", "dataclass"]))
+    }) {
+        return;
+    }
+
+    let Some(init_def) = class_def.body.iter().find_map(|stmt| match stmt {
+        Stmt::FunctionDef(function_def) if function_def.name == "__init__" => Some(function_def),
+        _ => None,
+    }) else {
+        return;
+    };
+
+    let mut visitor = SuperInitCallVisitor {
+        base_names: &base_names,
+        found: false,
+    };
+    visitor.visit_body(&init_def.body);
+
+    if !visitor.found {
+        checker.report_diagnostic(Diagnostic::new(MissingSuperCall, init_def.name.range()));
+    }
+}
+
+/// Looks for a call to `super().__init__(...)` or `Base.__init__(self, ...)`.
+struct SuperInitCallVisitor<'a> {
+    base_names: &'a [&'a str],
+    found: bool,
+}
+
+impl<'a> Visitor<'a> for SuperInitCallVisitor<'a> {
+    fn visit_stmt(&mut self, stmt: &'a Stmt) {
+        if self.found {
+            return;
+        }
+        match stmt {
+            Stmt::FunctionDef(_) | Stmt::ClassDef(_) => {
+                // Don't recurse into nested scopes.
+            }
+            _ => visitor::walk_stmt(self, stmt),
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &'a Expr) {
+        if self.found {
+            return;
+        }
+
+        if let Expr::Call(ast::ExprCall { func, .. }) = expr {
+            if let Expr::Attribute(ast::ExprAttribute { value, attr, .. }) = func.as_ref() {
+                if attr == "__init__" {
+                    let calls_super = matches!(
+                        value.as_ref(),
+                        Expr::Call(ast::ExprCall { func, .. })
+                            if matches!(func.as_ref(), Expr::Name(ast::ExprName { id, .. }) if id == "super")
+                    );
+                    let calls_base = matches!(
+                        value.as_ref(),
+                        Expr::Name(ast::ExprName { id, .. }) if self.base_names.contains(&id.as_str())
+                    );
+                    if calls_super || calls_base {
+                        self.found = true;
+                        return;
+                    }
+                }
+            }
+        }
+
+        visitor::walk_expr(self, expr);
+    }
+}
@@ -2,6 +2,7 @@ pub(crate) use and_or_ternary::*;
 pub(crate) use assert_on_string_literal::*;
 pub(crate) use await_outside_async::*;
 pub(crate) use bad_dunder_method_name::*;
+pub(crate) use bad_except_order::*;
 pub(crate) use bad_open_mode::*;
 pub(crate) use bad_staticmethod_argument::*;
 pub(crate) use bad_str_strip_call::*;
@@ -27,6 +28,7 @@ pub(crate) use if_stmt_min_max::*;
 pub(crate) use import_outside_top_level::*;
 pub(crate) use import_private_name::*;
 pub(crate) use import_self::*;
+pub(crate) use inconsistent_return_statements::*;
 pub(crate) use invalid_all_format::*;
 pub(crate) use invalid_all_object::*;
 pub(crate) use invalid_bool_return::*;
@@ -36,6 +38,7 @@ pub(crate) use invalid_envvar_value::*;
 pub(crate) use invalid_hash_return::*;
 pub(crate) use invalid_index_return::*;
 pub(crate) use invalid_length_return::*;
+pub(crate) use invalid_repr_return::*;
 pub(crate) use invalid_str_return::*;
 pub(crate) use invalid_string_characters::*;
 pub(crate) use iteration_over_set::*;
@@ -46,6 +49,7 @@ pub(crate) use logging::*;
 pub(crate) use magic_value_comparison::*;
 pub(crate) use manual_import_from::*;
 pub(crate) use misplaced_bare_raise::*;
+pub(crate) use missing_super_call::*;
 pub(crate) use modified_iterating_set::*;
 pub(crate) use named_expr_without_context::*;
 pub(crate) use nan_comparison::*;
@@ -78,9 +82,11 @@ pub(crate) use subprocess_popen_preexec_fn::*;
 pub(crate) use subprocess_run_without_check::*;
 pub(crate) use super_without_brackets::*;
 pub(crate) use sys_exit_alias::*;
+pub(crate) use tautological_chained_comparison::*;
 pub(crate) use too_many_arguments::*;
 pub(crate) use too_many_boolean_expressions::*;
 pub(crate) use too_many_branches::*;
+pub(crate) use too_many_global_statements::*;
 pub(crate) use too_many_locals::*;
 pub(crate) use too_many_nested_blocks::*;
 pub(crate) use too_many_positional_arguments::*;
@@ -111,6 +117,7 @@ mod and_or_ternary;
 mod assert_on_string_literal;
 mod await_outside_async;
 mod bad_dunder_method_name;
+mod bad_except_order;
 mod bad_open_mode;
 mod bad_staticmethod_argument;
 mod bad_str_strip_call;
@@ -136,6 +143,7 @@ mod if_stmt_min_max;
 mod import_outside_top_level;
 mod import_private_name;
 mod import_self;
+mod inconsistent_return_statements;
 mod invalid_all_format;
 mod invalid_all_object;
 mod invalid_bool_return;
@@ -145,6 +153,7 @@ mod invalid_envvar_value;
 mod invalid_hash_return;
 mod invalid_index_return;
 mod invalid_length_return;
+mod invalid_repr_return;
 mod invalid_str_return;
 mod invalid_string_characters;
 mod iteration_over_set;
@@ -155,6 +164,7 @@ mod logging;
 mod magic_value_comparison;
 mod manual_import_from;
 mod misplaced_bare_raise;
+mod missing_super_call;
 mod modified_iterating_set;
 mod named_expr_without_context;
 mod nan_comparison;
@@ -187,9 +197,11 @@ mod subprocess_popen_preexec_fn;
 mod subprocess_run_without_check;
 mod super_without_brackets;
 mod sys_exit_alias;
+mod tautological_chained_comparison;
 mod too_many_arguments;
 mod too_many_boolean_expressions;
 mod too_many_branches;
+mod too_many_global_statements;
 mod too_many_locals;
 mod too_many_nested_blocks;
 mod too_many_positional_arguments;
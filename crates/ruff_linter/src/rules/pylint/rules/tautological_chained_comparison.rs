@@ -0,0 +1,85 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::{self as ast, CmpOp, Expr};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for chained comparisons with integer-literal bounds that can never
+/// be satisfied.
+///
+/// ## Why is this bad?
+/// A chained comparison like `0 < x < 0` can never be true for any value of
+/// `x`. This usually indicates a mistake in one of the bounds.
+///
+/// ## Example
+/// ```python
+/// if 0 < x < 0:
+///     ...
+/// ```
+///
+/// ## References
+/// - [Python documentation: Comparisons](https://docs.python.org/3/reference/expressions.html#comparisons)
+#[derive(ViolationMetadata)]
+pub(crate) struct TautologicalChainedComparison;
+
+impl Violation for TautologicalChainedComparison {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        "Chained comparison always evaluates to `False`".to_string()
+    }
+}
+
+/// PLR6202
+pub(crate) fn tautological_chained_comparison(checker: &Checker, compare: &ast::ExprCompare) {
+    let [op1, op2] = compare.ops.as_ref() else {
+        return;
+    };
+    let [middle] = compare.comparators.as_ref() else {
+        return;
+    };
+    let Some(left) = as_int_literal(&compare.left) else {
+        return;
+    };
+    let Some(right) = as_int_literal(middle) else {
+        return;
+    };
+    // `left OP1 x OP2 right` — the middle comparator only matters as the
+    // shared operand, so we only need the two literal bounds and operators.
+    if !is_always_false(left, *op1, *op2, right) {
+        return;
+    }
+
+    checker.report_diagnostic(Diagnostic::new(
+        TautologicalChainedComparison,
+        compare.range(),
+    ));
+}
+
+/// Returns the integer value of `expr`, if it is an integer literal.
+fn as_int_literal(expr: &Expr) -> Option<i64> {
+    match expr {
+        Expr::NumberLiteral(ast::ExprNumberLiteral {
+            value: ast::Number::Int(int),
+            ..
+        }) => int.as_i64(),
+        _ => None,
+    }
+}
+
+/// Given `left OP1 _ OP2 right`, determine whether the comparison can never
+/// be satisfied, for any value of the shared (middle) operand.
+fn is_always_false(left: i64, op1: CmpOp, op2: CmpOp, right: i64) -> bool {
+    match (op1, op2) {
+        // `left < x < right` / `left < x <= right` / etc.
+        (CmpOp::Lt | CmpOp::LtE, CmpOp::Lt | CmpOp::LtE) => {
+            left > right || (left == right && !(op1 == CmpOp::LtE && op2 == CmpOp::LtE))
+        }
+        // `left > x > right` / `left > x >= right` / etc.
+        (CmpOp::Gt | CmpOp::GtE, CmpOp::Gt | CmpOp::GtE) => {
+            left < right || (left == right && !(op1 == CmpOp::GtE && op2 == CmpOp::GtE))
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,74 @@
+use rustc_hash::FxHashSet;
+
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::identifier::Identifier;
+use ruff_python_semantic::{Scope, ScopeKind};
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for functions that declare more `global` names than allowed.
+///
+/// By default, this rule allows up to five `global` names per function, as
+/// configured by the [`lint.pylint.max-globals`] option.
+///
+/// ## Why is this bad?
+/// A function that reaches into module-level state through many `global`
+/// declarations is tightly coupled to that state, which makes it harder to
+/// test and reason about in isolation. Prefer passing values in as
+/// arguments and returning results instead of mutating globals directly.
+///
+/// ## Example
+/// Assuming that `lint.pylint.max-globals` is set to 2:
+/// ```python
+/// def process():
+///     global a, b, c
+///     a = b + c
+/// ```
+///
+/// Use instead:
+/// ```python
+/// def process(b, c):
+///     return b + c
+/// ```
+///
+/// ## Options
+/// - `lint.pylint.max-globals`
+#[derive(ViolationMetadata)]
+pub(crate) struct TooManyGlobalStatements {
+    current_amount: usize,
+    max_amount: usize,
+}
+
+impl Violation for TooManyGlobalStatements {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let TooManyGlobalStatements {
+            current_amount,
+            max_amount,
+        } = self;
+        format!("Too many `global` names ({current_amount}/{max_amount})")
+    }
+}
+
+/// PLR0918
+pub(crate) fn too_many_global_statements(checker: &Checker, scope: &Scope) {
+    let num_globals = scope
+        .binding_ids()
+        .filter(|id| checker.semantic().binding(*id).is_global())
+        .map(|id| checker.semantic().binding(id).name(checker.source()))
+        .collect::<FxHashSet<_>>()
+        .len();
+    if num_globals > checker.settings.pylint.max_globals {
+        if let ScopeKind::Function(func) = scope.kind {
+            checker.report_diagnostic(Diagnostic::new(
+                TooManyGlobalStatements {
+                    current_amount: num_globals,
+                    max_amount: checker.settings.pylint.max_globals,
+                },
+                func.identifier(),
+            ));
+        }
+    }
+}
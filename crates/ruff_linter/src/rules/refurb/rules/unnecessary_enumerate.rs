@@ -35,6 +35,11 @@ use crate::fix::edits::pad;
 /// generators), and so refactoring to use `len` over `enumerate` is not always
 /// safe.
 ///
+/// If `enumerate` is called with a `start` argument and the index is unused,
+/// the `start` argument has no effect on the resulting values and is safe to
+/// drop; if the value is unused and `start` is anything other than `0`, no fix
+/// is suggested, since `range(len(...))` has no equivalent starting offset.
+///
 /// ## Example
 /// ```python
 /// for index, _ in enumerate(sequence):
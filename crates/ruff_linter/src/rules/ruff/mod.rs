@@ -100,6 +100,44 @@ mod tests {
     #[test_case(Rule::UnusedUnpackedVariable, Path::new("RUF059_2.py"))]
     #[test_case(Rule::UnusedUnpackedVariable, Path::new("RUF059_3.py"))]
     #[test_case(Rule::InEmptyCollection, Path::new("RUF060.py"))]
+    #[test_case(Rule::DeleteUnassignedAttribute, Path::new("RUF061.py"))]
+    #[test_case(Rule::ComprehensionShadowsParameter, Path::new("RUF062.py"))]
+    #[test_case(Rule::NotImplementedReturnValue, Path::new("RUF063.py"))]
+    #[test_case(Rule::PathConstructorConcatenation, Path::new("RUF064.py"))]
+    #[test_case(Rule::InvalidSelfOutsideClass, Path::new("RUF065.py"))]
+    #[test_case(Rule::UnreachableAssertNever, Path::new("RUF066.py"))]
+    #[test_case(Rule::DictCallWithDoubleStarArgs, Path::new("RUF067.py"))]
+    #[test_case(Rule::AwaitNonAwaitable, Path::new("RUF068.py"))]
+    #[test_case(Rule::RaiseInDel, Path::new("RUF069.py"))]
+    #[test_case(Rule::OverloadWithoutImplementation, Path::new("RUF070.py"))]
+    #[test_case(Rule::UnnecessaryDictGetNoneDefault, Path::new("RUF071.py"))]
+    #[test_case(Rule::TypeVarBoundAndConstraints, Path::new("RUF072.py"))]
+    #[test_case(Rule::MutablePartialArgument, Path::new("RUF073.py"))]
+    #[test_case(Rule::RedundantCodecRoundtrip, Path::new("RUF074.py"))]
+    #[test_case(Rule::ExecOrEvalSyntaxError, Path::new("RUF075.py"))]
+    #[test_case(Rule::ReturnedClosedFile, Path::new("RUF076.py"))]
+    #[test_case(Rule::UndeclaredPublicName, Path::new("RUF077.py"))]
+    #[test_case(Rule::MisplacedDescriptorDecorator, Path::new("RUF078.py"))]
+    #[test_case(Rule::IncompatibleContainerComparison, Path::new("RUF079.py"))]
+    #[test_case(Rule::NoneReturningMethodAssignment, Path::new("RUF080.py"))]
+    #[test_case(Rule::InvalidTypeAliasValue, Path::new("RUF081.py"))]
+    #[test_case(Rule::WindowsPathStringLiteral, Path::new("RUF082.py"))]
+    #[test_case(Rule::SysExitWithMessageOutsideMain, Path::new("RUF083.py"))]
+    #[test_case(Rule::AssertOnConstant, Path::new("RUF084.py"))]
+    #[test_case(Rule::ReturnInNoneReturnFunction, Path::new("RUF085.py"))]
+    #[test_case(Rule::BaseExceptionCaught, Path::new("RUF086.py"))]
+    #[test_case(Rule::AssignmentUsedOnlyInAssert, Path::new("RUF087.py"))]
+    #[test_case(Rule::UnnecessaryIterableCastInCall, Path::new("RUF088.py"))]
+    #[test_case(Rule::IfElseBlockInsteadOfGetattr, Path::new("RUF089.py"))]
+    #[test_case(Rule::NestedTernary, Path::new("RUF090.py"))]
+    #[test_case(Rule::DebugGuardedBlock, Path::new("RUF091.py"))]
+    #[test_case(Rule::DuplicateDecorator, Path::new("RUF092.py"))]
+    #[test_case(Rule::ExitSuppressesException, Path::new("RUF093.py"))]
+    #[test_case(Rule::LenCompareToZero, Path::new("RUF094.py"))]
+    #[test_case(Rule::UnhashableKeyOrElement, Path::new("RUF095.py"))]
+    #[test_case(Rule::RaiseFromNone, Path::new("RUF096.py"))]
+    #[test_case(Rule::ParameterReassignment, Path::new("RUF097.py"))]
+    #[test_case(Rule::EnumMixinBaseOrder, Path::new("RUF098.py"))]
     #[test_case(Rule::RedirectedNOQA, Path::new("RUF101_0.py"))]
     #[test_case(Rule::RedirectedNOQA, Path::new("RUF101_1.py"))]
     #[test_case(Rule::InvalidRuleCode, Path::new("RUF102.py"))]
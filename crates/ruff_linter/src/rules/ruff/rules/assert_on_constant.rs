@@ -0,0 +1,110 @@
+use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Edit, Fix};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::helpers::{is_const_false, Truthiness};
+use ruff_python_ast::{Expr, Stmt};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+use crate::fix::edits::delete_stmt;
+
+/// ## What it does
+/// Checks for `assert` statements whose test condition is a constant number,
+/// boolean, `None`, or `...` (other than `False`, which is covered by
+/// `assert-false`).
+///
+/// ## Why is this bad?
+/// An `assert` with a truthy constant condition (e.g., `assert True`,
+/// `assert 1`) is a no-op: it never raises, so it can be removed. An
+/// `assert` with a falsy constant condition (e.g., `assert None`, `assert
+/// 0`) always raises `AssertionError`, and is clearer when written as an
+/// explicit `raise AssertionError`.
+///
+/// ## Example
+/// ```python
+/// assert True
+/// assert 0
+/// ```
+///
+/// Use instead:
+/// ```python
+/// raise AssertionError
+/// ```
+///
+/// ## Fix safety
+/// This rule's fix is marked as unsafe. Removing a no-op assertion, or
+/// converting an always-failing assertion to a `raise`, changes the
+/// behavior of your program when running in optimized mode (`python -O`),
+/// which skips `assert` statements entirely.
+///
+/// ## References
+/// - [Python documentation: `assert`](https://docs.python.org/3/reference/simple_stmts.html#the-assert-statement)
+#[derive(ViolationMetadata)]
+pub(crate) struct AssertOnConstant {
+    kind: Kind,
+}
+
+impl AlwaysFixableViolation for AssertOnConstant {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        match self.kind {
+            Kind::NoOp => "Assert test is always true, which is a no-op".to_string(),
+            Kind::AlwaysFails => "Assert test is always false, which always raises".to_string(),
+        }
+    }
+
+    fn fix_title(&self) -> String {
+        match self.kind {
+            Kind::NoOp => "Remove assertion".to_string(),
+            Kind::AlwaysFails => "Replace with `raise AssertionError`".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+enum Kind {
+    NoOp,
+    AlwaysFails,
+}
+
+/// RUF084
+pub(crate) fn assert_on_constant(checker: &Checker, stmt: &Stmt, test: &Expr) {
+    // `assert False` is already covered by `AssertFalse`, and string/bytes/f-string
+    // literals are already covered by `AssertOnStringLiteral`.
+    if is_const_false(test) {
+        return;
+    }
+    if !matches!(
+        test,
+        Expr::NumberLiteral(_)
+            | Expr::BooleanLiteral(_)
+            | Expr::NoneLiteral(_)
+            | Expr::EllipsisLiteral(_)
+    ) {
+        return;
+    }
+
+    let semantic = checker.semantic();
+    let Some(is_true) =
+        Truthiness::from_expr(test, |id| semantic.has_builtin_binding(id)).into_bool()
+    else {
+        return;
+    };
+
+    let kind = if is_true { Kind::NoOp } else { Kind::AlwaysFails };
+
+    let mut diagnostic = Diagnostic::new(AssertOnConstant { kind }, test.range());
+    let fix = match kind {
+        Kind::NoOp => {
+            let parent = semantic.current_statement_parent();
+            let edit = delete_stmt(stmt, parent, checker.locator(), checker.indexer());
+            Fix::unsafe_edit(edit)
+                .isolate(Checker::isolation(semantic.current_statement_parent_id()))
+        }
+        Kind::AlwaysFails => Fix::unsafe_edit(Edit::range_replacement(
+            "raise AssertionError".to_string(),
+            stmt.range(),
+        )),
+    };
+    diagnostic.set_fix(fix);
+    checker.report_diagnostic(diagnostic);
+}
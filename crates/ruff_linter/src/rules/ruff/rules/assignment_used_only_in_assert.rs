@@ -0,0 +1,93 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_semantic::Binding;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for variables whose only references occur within `assert`
+/// statements.
+///
+/// ## Why is this bad?
+/// When the Python interpreter is run under the `-O` flag, `assert`
+/// statements (along with their test expressions) are not executed. If a
+/// variable is assigned a value that's only ever read from within `assert`
+/// statements, the assignment becomes dead code in optimized mode, even
+/// though the variable does appear to be "used" when read at face value.
+///
+/// ## Example
+/// ```python
+/// def get_user(user_id):
+///     user = fetch_user(user_id)
+///     debug_info = f"looked up user {user_id}: {user}"
+///     assert user is not None, debug_info
+///     return user
+/// ```
+///
+/// Use instead:
+/// ```python
+/// def get_user(user_id):
+///     user = fetch_user(user_id)
+///     assert user is not None, f"looked up user {user_id}: {user}"
+///     return user
+/// ```
+///
+/// ## Known problems
+/// This rule is disabled by default because it's common, and often
+/// intentional, to assign a variable that's only used to build an `assert`
+/// message, without ever intending to run the code under `-O`.
+///
+/// This rule does not flag named expressions (e.g., `assert (y := x**2), y`),
+/// which are covered by `assignment-in-assert`.
+///
+/// ## References
+/// - [Python documentation: `-O`](https://docs.python.org/3/using/cmdline.html#cmdoption-O)
+#[derive(ViolationMetadata)]
+pub(crate) struct AssignmentUsedOnlyInAssert {
+    name: String,
+}
+
+impl Violation for AssignmentUsedOnlyInAssert {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let AssignmentUsedOnlyInAssert { name } = self;
+        format!("Local variable `{name}` is only referenced from `assert` statements")
+    }
+}
+
+/// RUF087
+pub(crate) fn assignment_used_only_in_assert(
+    checker: &Checker,
+    binding: &Binding,
+) -> Option<Diagnostic> {
+    // Named expressions assigned inside `assert` statements are covered by
+    // `assignment-in-assert` instead.
+    if binding.in_assert_statement() {
+        return None;
+    }
+    if !binding.kind.is_assignment() {
+        return None;
+    }
+    if binding.is_global() || binding.is_nonlocal() {
+        return None;
+    }
+    if binding.is_unused() {
+        return None;
+    }
+
+    let semantic = checker.semantic();
+    if !binding
+        .references()
+        .all(|reference| semantic.reference(reference).in_assert_statement())
+    {
+        return None;
+    }
+
+    Some(Diagnostic::new(
+        AssignmentUsedOnlyInAssert {
+            name: binding.name(checker.source()).to_string(),
+        },
+        binding.range(),
+    ))
+}
@@ -0,0 +1,77 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::{Expr, ExprAwait};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `await` expressions applied to a value that is guaranteed to
+/// not be awaitable.
+///
+/// ## Why is this bad?
+/// Awaiting a literal, or the result of a builtin call that's known to
+/// return a plain, non-awaitable value (like `len` or `str`), always raises
+/// `TypeError` at runtime. This is almost always a mistake, such as
+/// forgetting to call the coroutine function you meant to await, or
+/// misplacing an `await` on a synchronous expression.
+///
+/// ## Example
+/// ```python
+/// async def f(items):
+///     return await len(items)
+/// ```
+///
+/// Use instead:
+/// ```python
+/// async def f(items):
+///     return len(items)
+/// ```
+#[derive(ViolationMetadata)]
+pub(crate) struct AwaitNonAwaitable;
+
+impl Violation for AwaitNonAwaitable {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        "`await` used on a value that is never awaitable, which will raise `TypeError`"
+            .to_string()
+    }
+}
+
+/// Builtins whose return value is never awaitable.
+const NON_AWAITABLE_BUILTINS: &[&str] = &[
+    "abs", "bin", "bool", "chr", "dict", "float", "format", "hasattr", "hash", "hex", "int",
+    "isinstance", "issubclass", "len", "list", "max", "min", "oct", "ord", "repr", "round",
+    "set", "sorted", "str", "sum", "tuple",
+];
+
+/// RUF068
+pub(crate) fn await_non_awaitable(checker: &Checker, await_expr: &ExprAwait) {
+    let value = &*await_expr.value;
+
+    let is_non_awaitable = match value {
+        // Literals can never be awaitable.
+        Expr::NumberLiteral(_)
+        | Expr::StringLiteral(_)
+        | Expr::BytesLiteral(_)
+        | Expr::BooleanLiteral(_)
+        | Expr::NoneLiteral(_)
+        | Expr::EllipsisLiteral(_)
+        | Expr::List(_)
+        | Expr::Dict(_)
+        | Expr::Set(_)
+        | Expr::Tuple(_) => true,
+        // Calls to certain builtins are known to never return an awaitable.
+        Expr::Call(call) => checker
+            .semantic()
+            .resolve_builtin_symbol(&call.func)
+            .is_some_and(|name| NON_AWAITABLE_BUILTINS.contains(&name)),
+        _ => false,
+    };
+
+    if !is_non_awaitable {
+        return;
+    }
+
+    checker.report_diagnostic(Diagnostic::new(AwaitNonAwaitable, await_expr.range()));
+}
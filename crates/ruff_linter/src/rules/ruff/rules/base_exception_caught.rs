@@ -0,0 +1,144 @@
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::statement_visitor::{walk_stmt, StatementVisitor};
+use ruff_python_ast::{self as ast, Expr, Stmt};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `except` clauses that catch `BaseException`.
+///
+/// ## Why is this bad?
+/// `BaseException` is the root of the exception hierarchy, and includes
+/// exceptions that are not meant to be handled by application code, such as
+/// `KeyboardInterrupt` and `SystemExit`. Catching `BaseException` will
+/// intercept these exceptions too, which can prevent a program from
+/// responding to Ctrl-C or from exiting via `sys.exit`.
+///
+/// In almost all cases, catching `Exception` is sufficient, and is what was
+/// likely intended.
+///
+/// ## Example
+/// ```python
+/// try:
+///     foo()
+/// except BaseException:
+///     ...
+/// ```
+///
+/// Use instead:
+/// ```python
+/// try:
+///     foo()
+/// except Exception:
+///     ...
+/// ```
+///
+/// Exceptions that are re-raised will _not_ be flagged, as they're expected
+/// to be caught elsewhere:
+/// ```python
+/// try:
+///     foo()
+/// except BaseException:
+///     raise
+/// ```
+///
+/// ## Fix safety
+/// This rule's fix is unsafe. Replacing `BaseException` with `Exception`
+/// narrows the set of exceptions that are caught, which can change the
+/// behavior of your program if it relies on catching `KeyboardInterrupt`,
+/// `SystemExit`, or another direct subclass of `BaseException`.
+///
+/// ## References
+/// - [Python documentation: Exception hierarchy](https://docs.python.org/3/library/exceptions.html#exception-hierarchy)
+#[derive(ViolationMetadata)]
+pub(crate) struct BaseExceptionCaught;
+
+impl Violation for BaseExceptionCaught {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        "Do not catch `BaseException`; it includes `KeyboardInterrupt` and `SystemExit`, which are usually not meant to be handled".to_string()
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Replace with `Exception`".to_string())
+    }
+}
+
+/// RUF086
+pub(crate) fn base_exception_caught(
+    checker: &Checker,
+    type_: Option<&Expr>,
+    name: Option<&str>,
+    body: &[Stmt],
+) {
+    let Some(type_) = type_ else {
+        return;
+    };
+
+    let semantic = checker.semantic();
+    if !semantic
+        .resolve_builtin_symbol(type_)
+        .is_some_and(|builtin| builtin == "BaseException")
+    {
+        return;
+    }
+
+    // If the exception is re-raised, don't flag an error; this is a legitimate
+    // cleanup pattern (e.g., `except BaseException: cleanup(); raise`).
+    let mut visitor = ReraiseVisitor::new(name);
+    visitor.visit_body(body);
+    if visitor.seen {
+        return;
+    }
+
+    let mut diagnostic = Diagnostic::new(BaseExceptionCaught, type_.range());
+    if matches!(type_, Expr::Name(_)) {
+        diagnostic.set_fix(Fix::unsafe_edit(Edit::range_replacement(
+            "Exception".to_string(),
+            type_.range(),
+        )));
+    }
+    checker.report_diagnostic(diagnostic);
+}
+
+/// A visitor to detect whether the exception with the given name was re-raised.
+struct ReraiseVisitor<'a> {
+    name: Option<&'a str>,
+    seen: bool,
+}
+
+impl<'a> ReraiseVisitor<'a> {
+    fn new(name: Option<&'a str>) -> Self {
+        Self { name, seen: false }
+    }
+}
+
+impl<'a> StatementVisitor<'a> for ReraiseVisitor<'a> {
+    fn visit_stmt(&mut self, stmt: &'a Stmt) {
+        match stmt {
+            Stmt::Raise(ast::StmtRaise { exc, cause, .. }) => {
+                if let Some(cause) = cause {
+                    if let Expr::Name(ast::ExprName { id, .. }) = cause.as_ref() {
+                        if self.name.is_some_and(|name| id == name) {
+                            self.seen = true;
+                        }
+                    }
+                } else if let Some(exc) = exc {
+                    if let Expr::Name(ast::ExprName { id, .. }) = exc.as_ref() {
+                        if self.name.is_some_and(|name| id == name) {
+                            self.seen = true;
+                        }
+                    }
+                } else {
+                    self.seen = true;
+                }
+            }
+            Stmt::Try(_) | Stmt::FunctionDef(_) | Stmt::ClassDef(_) => {}
+            _ => walk_stmt(self, stmt),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::{self as ast, Comprehension, Expr};
+use ruff_python_semantic::ScopeKind;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for comprehension variables that shadow a parameter of an
+/// enclosing function.
+///
+/// ## Why is this bad?
+/// A comprehension introduces its own scope for its loop variable(s), so a
+/// comprehension variable with the same name as a parameter of the
+/// enclosing function hides that parameter for the duration of the
+/// comprehension. This is confusing and often unintentional, since the
+/// comprehension's result won't actually depend on the parameter.
+///
+/// ## Example
+/// ```python
+/// def f(x):
+///     return [x for x in range(10)]
+/// ```
+///
+/// Use instead:
+/// ```python
+/// def f(x):
+///     return [i for i in range(10)]
+/// ```
+#[derive(ViolationMetadata)]
+pub(crate) struct ComprehensionShadowsParameter {
+    name: String,
+}
+
+impl Violation for ComprehensionShadowsParameter {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let ComprehensionShadowsParameter { name } = self;
+        format!("Comprehension variable `{name}` shadows parameter of enclosing function")
+    }
+}
+
+/// RUF062
+pub(crate) fn comprehension_shadows_parameter(checker: &Checker, comprehension: &Comprehension) {
+    let Some(parameters) = checker
+        .semantic()
+        .current_scopes()
+        .find_map(|scope| match scope.kind {
+            ScopeKind::Function(ast::StmtFunctionDef { parameters, .. }) => Some(parameters),
+            ScopeKind::Lambda(ast::ExprLambda { parameters, .. }) => parameters.as_deref(),
+            _ => None,
+        })
+    else {
+        return;
+    };
+
+    for name in comprehension_target_names(&comprehension.target) {
+        if name == "_" {
+            continue;
+        }
+        if parameters.includes(name) {
+            checker.report_diagnostic(Diagnostic::new(
+                ComprehensionShadowsParameter {
+                    name: name.to_string(),
+                },
+                comprehension.target.range(),
+            ));
+        }
+    }
+}
+
+/// Returns the names bound by a comprehension target, recursing into tuple and list unpacking.
+fn comprehension_target_names(target: &Expr) -> Vec<&str> {
+    match target {
+        Expr::Name(ast::ExprName { id, .. }) => vec![id.as_str()],
+        Expr::Tuple(ast::ExprTuple { elts, .. }) | Expr::List(ast::ExprList { elts, .. }) => {
+            elts.iter().flat_map(comprehension_target_names).collect()
+        }
+        Expr::Starred(ast::ExprStarred { value, .. }) => comprehension_target_names(value),
+        _ => Vec::new(),
+    }
+}
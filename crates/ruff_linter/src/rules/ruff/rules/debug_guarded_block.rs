@@ -0,0 +1,65 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::{self as ast, Expr, UnaryOp};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `if __debug__:` and `if not __debug__:` blocks.
+///
+/// ## Why is this bad?
+/// `__debug__` is `True` unless Python is run with the `-O` (or `-OO`) flag,
+/// in which case it's `False` and `assert` statements are skipped entirely.
+/// A block guarded by `if __debug__:` therefore behaves like a bundle of
+/// `assert` statements: it runs during normal development and testing, but
+/// silently disappears under `-O`. A block guarded by `if not __debug__:` is
+/// the opposite: dead code during normal execution, and only reachable when
+/// running under `-O`.
+///
+/// Either pattern is easy to overlook, since the code appears to run
+/// unconditionally when read casually. If the guarded code has
+/// user-visible side effects, running the interpreter with `-O` will
+/// silently change your program's behavior.
+///
+/// ## Example
+/// ```python
+/// if __debug__:
+///     validate(data)
+/// ```
+#[derive(ViolationMetadata)]
+pub(crate) struct DebugGuardedBlock {
+    negated: bool,
+}
+
+impl Violation for DebugGuardedBlock {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let DebugGuardedBlock { negated } = self;
+        if *negated {
+            "This block is dead code unless Python is run with `-O`".to_string()
+        } else {
+            "This block is skipped entirely when Python is run with `-O`".to_string()
+        }
+    }
+}
+
+/// RUF091
+pub(crate) fn debug_guarded_block(checker: &Checker, stmt_if: &ast::StmtIf) {
+    let test = stmt_if.test.as_ref();
+
+    let (target, negated) = match test {
+        Expr::UnaryOp(ast::ExprUnaryOp {
+            op: UnaryOp::Not,
+            operand,
+            ..
+        }) => (operand.as_ref(), true),
+        _ => (test, false),
+    };
+
+    if !checker.semantic().match_builtin_expr(target, "__debug__") {
+        return;
+    }
+
+    checker.report_diagnostic(Diagnostic::new(DebugGuardedBlock { negated }, test.range()));
+}
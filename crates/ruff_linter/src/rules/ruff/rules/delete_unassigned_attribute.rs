@@ -0,0 +1,124 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::{self as ast, Expr, Stmt, StmtDelete};
+use ruff_python_semantic::ScopeKind;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `del self.<attr>` in `__init__` methods, where `<attr>` has
+/// not yet been assigned.
+///
+/// ## Why is this bad?
+/// Deleting an attribute that was never set will raise an `AttributeError`
+/// at runtime. Finding `del self.<attr>` in `__init__` before any
+/// assignment to `self.<attr>` is usually a leftover from refactoring
+/// (e.g., an attribute that was renamed or moved) rather than intentional
+/// behavior.
+///
+/// ## Example
+/// ```python
+/// class Widget:
+///     def __init__(self):
+///         del self.cache
+///         self.cache = {}
+/// ```
+///
+/// Use instead:
+/// ```python
+/// class Widget:
+///     def __init__(self):
+///         self.cache = {}
+/// ```
+#[derive(ViolationMetadata)]
+pub(crate) struct DeleteUnassignedAttribute {
+    name: String,
+}
+
+impl Violation for DeleteUnassignedAttribute {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let DeleteUnassignedAttribute { name } = self;
+        format!("Deletion of attribute `{name}` before it is assigned in `__init__`")
+    }
+}
+
+/// RUF061
+pub(crate) fn delete_unassigned_attribute(checker: &Checker, delete: &StmtDelete) {
+    let ScopeKind::Function(ast::StmtFunctionDef {
+        name: "__init__",
+        parameters,
+        body,
+        ..
+    }) = checker.semantic().current_scope().kind
+    else {
+        return;
+    };
+
+    let Some(self_name) = parameters
+        .posonlyargs
+        .first()
+        .or_else(|| parameters.args.first())
+        .map(|parameter| parameter.name().as_str())
+    else {
+        return;
+    };
+
+    for target in &delete.targets {
+        let Expr::Attribute(ast::ExprAttribute { value, attr, .. }) = target else {
+            continue;
+        };
+        let Expr::Name(ast::ExprName { id, .. }) = value.as_ref() else {
+            continue;
+        };
+        if id != self_name {
+            continue;
+        }
+        if is_assigned_before(body, self_name, attr.as_str(), delete) {
+            continue;
+        }
+        checker.report_diagnostic(Diagnostic::new(
+            DeleteUnassignedAttribute {
+                name: attr.to_string(),
+            },
+            target.range(),
+        ));
+    }
+}
+
+/// Returns `true` if `self.<attr>` is assigned by a top-level statement in `body` that precedes
+/// `delete` (textually).
+fn is_assigned_before(body: &[Stmt], self_name: &str, attr: &str, delete: &StmtDelete) -> bool {
+    for stmt in body {
+        if stmt.start() >= delete.start() {
+            break;
+        }
+        let Stmt::Assign(ast::StmtAssign { targets, .. }) = stmt else {
+            continue;
+        };
+        for target in targets {
+            if is_attribute_target(target, self_name, attr) {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Returns `true` if `target` is `self.<attr>` (or a tuple/list element thereof).
+fn is_attribute_target(target: &Expr, self_name: &str, attr: &str) -> bool {
+    match target {
+        Expr::Attribute(ast::ExprAttribute { value, attr: name, .. }) => {
+            name.as_str() == attr
+                && matches!(value.as_ref(), Expr::Name(ast::ExprName { id, .. }) if id == self_name)
+        }
+        Expr::Tuple(ast::ExprTuple { elts, .. }) | Expr::List(ast::ExprList { elts, .. }) => elts
+            .iter()
+            .any(|element| is_attribute_target(element, self_name, attr)),
+        Expr::Starred(ast::ExprStarred { value, .. }) => {
+            is_attribute_target(value, self_name, attr)
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,101 @@
+use itertools::Itertools;
+
+use ruff_diagnostics::{Applicability, Diagnostic, Edit, Fix, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::{self as ast, PythonVersion};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `dict()` calls that merge two or more mappings via `**`
+/// unpacking.
+///
+/// ## Why is this bad?
+/// Building a dictionary this way requires a builtin lookup and a function
+/// call, whereas a dict literal (or, for two mappings and no extra keyword
+/// arguments, the `|` merge operator on Python 3.9+) is faster and more
+/// direct.
+///
+/// ## Example
+/// ```python
+/// merged = dict(**a, **b)
+/// merged = dict(**a, x=1)
+/// ```
+///
+/// Use instead:
+/// ```python
+/// merged = a | b
+/// merged = {**a, "x": 1}
+/// ```
+///
+/// ## Fix safety
+/// This rule's fix is marked as unsafe, as it may drop comments within the
+/// original call, and may change behavior if `a` or `b` is not actually a
+/// mapping (in which case `dict()` would raise, while `{**a, **b}` and
+/// `a | b` may fail differently or not at all until iterated).
+#[derive(ViolationMetadata)]
+pub(crate) struct DictCallWithDoubleStarArgs;
+
+impl Violation for DictCallWithDoubleStarArgs {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        "Use a dict literal or `|` instead of `dict()` with `**` keyword arguments".to_string()
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Replace with a dict literal or `|`".to_string())
+    }
+}
+
+/// RUF067
+pub(crate) fn dict_call_with_double_star_args(checker: &Checker, call: &ast::ExprCall) {
+    if !call.arguments.args.is_empty() {
+        return;
+    }
+    let [_, _, ..] = call.arguments.keywords.as_ref() else {
+        return;
+    };
+    if !call.arguments.keywords.iter().any(|kw| kw.arg.is_none()) {
+        // No `**` unpacking; this is `dict(a=1, b=2)`, handled by C408 instead.
+        return;
+    }
+    if !checker
+        .semantic()
+        .resolve_builtin_symbol(&call.func)
+        .is_some_and(|name| name == "dict")
+    {
+        return;
+    }
+
+    let all_double_starred = call.arguments.keywords.iter().all(|kw| kw.arg.is_none());
+
+    let replacement = if all_double_starred && checker.target_version() >= PythonVersion::PY39 {
+        call.arguments
+            .keywords
+            .iter()
+            .map(|kw| checker.source_slice(kw.value.range()))
+            .join(" | ")
+    } else {
+        format!(
+            "{{{}}}",
+            call.arguments
+                .keywords
+                .iter()
+                .map(|kw| {
+                    let value = checker.source_slice(kw.value.range());
+                    match &kw.arg {
+                        Some(name) => format!("{:?}: {value}", name.as_str()),
+                        None => format!("**{value}"),
+                    }
+                })
+                .join(", ")
+        )
+    };
+
+    let diagnostic = Diagnostic::new(DictCallWithDoubleStarArgs, call.range());
+    checker.report_diagnostic(diagnostic.with_fix(Fix::applicable_edit(
+        Edit::range_replacement(replacement, call.range()),
+        Applicability::Unsafe,
+    )));
+}
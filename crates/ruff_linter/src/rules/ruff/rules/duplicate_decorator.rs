@@ -0,0 +1,92 @@
+use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Edit, Fix};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::name::QualifiedName;
+use ruff_python_ast::{self as ast, Decorator, Expr};
+use ruff_source_file::LineRanges;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for duplicate decorators on a function or class definition.
+///
+/// ## Why is this bad?
+/// Applying the same decorator to a function or class more than once is
+/// almost always a mistake. At best, the decorator's effect (e.g., caching)
+/// is applied redundantly; at worst, it changes behavior in a way the
+/// author didn't intend.
+///
+/// Decorators are only considered duplicates if they resolve to the same
+/// qualified name and, for parametrized decorators, are called with the
+/// same arguments (as compared by source text). `@foo(1)` and `@foo(2)`
+/// are therefore not considered duplicates.
+///
+/// ## Example
+/// ```python
+/// @cache
+/// @cache
+/// def compute():
+///     ...
+/// ```
+///
+/// Use instead:
+/// ```python
+/// @cache
+/// def compute():
+///     ...
+/// ```
+#[derive(ViolationMetadata)]
+pub(crate) struct DuplicateDecorator {
+    name: String,
+}
+
+impl AlwaysFixableViolation for DuplicateDecorator {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let DuplicateDecorator { name } = self;
+        format!("`@{name}` is applied more than once")
+    }
+
+    fn fix_title(&self) -> String {
+        "Remove duplicate decorator".to_string()
+    }
+}
+
+/// RUF092
+pub(crate) fn duplicate_decorator(checker: &Checker, decorator_list: &[Decorator]) {
+    let mut seen: Vec<(QualifiedName, Option<&str>)> = Vec::new();
+
+    for decorator in decorator_list {
+        let (callee, arguments) = match &decorator.expression {
+            Expr::Call(ast::ExprCall {
+                func, arguments, ..
+            }) => (func.as_ref(), Some(arguments)),
+            expression => (expression, None),
+        };
+
+        let Some(qualified_name) = checker.semantic().resolve_qualified_name(callee) else {
+            continue;
+        };
+
+        // For parametrized decorators, compare the argument list by source text, so that
+        // `@foo(1)` and `@foo(2)` aren't flagged as duplicates.
+        let arguments_source = arguments.map(|arguments| checker.locator().slice(arguments));
+
+        let key = (qualified_name, arguments_source);
+        if seen.contains(&key) {
+            let mut diagnostic = Diagnostic::new(
+                DuplicateDecorator {
+                    name: key.0.to_string(),
+                },
+                decorator.range(),
+            );
+            let edit = Edit::range_deletion(
+                checker.locator().full_lines_range(decorator.range()),
+            );
+            diagnostic.set_fix(Fix::unsafe_edit(edit));
+            checker.report_diagnostic(diagnostic);
+        } else {
+            seen.push(key);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::{Arguments, Expr, StmtClassDef};
+use ruff_python_semantic::analyze::class::is_enumeration;
+use ruff_python_semantic::SemanticModel;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for enum classes that mix in a concrete data type (such as `int`
+/// or `str`) after `Enum` (or one of its variants) in their base class list.
+///
+/// ## Why is this bad?
+/// When mixing a data type into an `Enum`, the data type must come *before*
+/// `Enum` in the base class list:
+///
+/// ```python
+/// class Color(int, Enum): ...
+/// ```
+///
+/// Writing the bases in the other order:
+///
+/// ```python
+/// class Color(Enum, int): ...
+/// ```
+///
+/// still creates a class, but it no longer behaves as a mixed-in enum: the
+/// resulting members aren't instances of `int`, and comparisons and
+/// formatting that rely on the mixin (`Color.RED + 1`, `f"{Color.RED:d}"`)
+/// fail at runtime.
+///
+/// ## Example
+/// ```python
+/// from enum import Enum
+///
+///
+/// class Color(Enum, int):
+///     RED = 1
+/// ```
+///
+/// Use instead:
+/// ```python
+/// from enum import Enum
+///
+///
+/// class Color(int, Enum):
+///     RED = 1
+/// ```
+///
+/// ## References
+/// - [Python documentation: `enum` &sect; Mixed-in types](https://docs.python.org/3/howto/enum.html#mixed-in-types)
+#[derive(ViolationMetadata)]
+pub(crate) struct EnumMixinBaseOrder {
+    data_type: String,
+}
+
+impl Violation for EnumMixinBaseOrder {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let EnumMixinBaseOrder { data_type } = self;
+        format!("Mixed-in data type `{data_type}` must come before `Enum` in the base class list")
+    }
+}
+
+/// RUF098
+pub(crate) fn enum_mixin_base_order(checker: &Checker, class_def: &StmtClassDef) {
+    let semantic = checker.semantic();
+
+    if !is_enumeration(class_def, semantic) {
+        return;
+    }
+
+    let Some(Arguments { args: bases, .. }) = class_def.arguments.as_deref() else {
+        return;
+    };
+
+    let Some(enum_index) = bases.iter().position(|base| is_enum_base(base, semantic)) else {
+        return;
+    };
+
+    for base in &bases[enum_index + 1..] {
+        let Some(data_type) = mixin_data_type_name(base, semantic) else {
+            continue;
+        };
+        checker.report_diagnostic(Diagnostic::new(
+            EnumMixinBaseOrder { data_type },
+            base.range(),
+        ));
+    }
+}
+
+/// Returns `true` if `base` resolves to `enum.Enum` or one of its variants.
+fn is_enum_base(base: &Expr, semantic: &SemanticModel) -> bool {
+    semantic.resolve_qualified_name(base).is_some_and(|name| {
+        matches!(
+            name.segments(),
+            [
+                "enum",
+                "Enum" | "Flag" | "IntEnum" | "IntFlag" | "StrEnum" | "ReprEnum"
+            ]
+        )
+    })
+}
+
+/// Returns the name of `base` if it's one of the builtin types commonly mixed into an enum.
+fn mixin_data_type_name(base: &Expr, semantic: &SemanticModel) -> Option<String> {
+    ["int", "str", "float", "bool"]
+        .into_iter()
+        .find(|builtin| semantic.match_builtin_expr(base, builtin))
+        .map(ToString::to_string)
+}
@@ -0,0 +1,79 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::{self as ast, Expr, ExprCall};
+use ruff_python_parser::{parse_expression, parse_module};
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `exec` and `eval` calls whose first argument is a string literal
+/// that is not valid Python syntax.
+///
+/// ## Why is this bad?
+/// `exec` and `eval` don't raise a `SyntaxError` until the code actually runs,
+/// so a typo or other syntax error in the string can go unnoticed until the
+/// `exec`/`eval` call is hit at runtime, which may be much later than when
+/// the string was written (or may not happen at all, e.g., if the call is on
+/// a rarely exercised code path).
+///
+/// ## Example
+/// ```python
+/// exec("x = ")
+/// ```
+///
+/// ## Known problems
+/// This rule only validates that the string parses as Python syntax; it
+/// doesn't run any other lint rules against the code inside the string, and
+/// it can't detect `NameError`s or other issues that only surface at
+/// evaluation time.
+#[derive(ViolationMetadata)]
+pub(crate) struct ExecOrEvalSyntaxError {
+    builtin: &'static str,
+    parse_error: String,
+}
+
+impl Violation for ExecOrEvalSyntaxError {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let ExecOrEvalSyntaxError {
+            builtin,
+            parse_error,
+        } = self;
+        format!("Syntax error in `{builtin}` string: {parse_error}")
+    }
+}
+
+/// RUF075
+pub(crate) fn exec_or_eval_syntax_error(checker: &Checker, call: &ExprCall) {
+    let semantic = checker.semantic();
+
+    let builtin = if semantic.match_builtin_expr(&call.func, "exec") {
+        "exec"
+    } else if semantic.match_builtin_expr(&call.func, "eval") {
+        "eval"
+    } else {
+        return;
+    };
+
+    let Some(Expr::StringLiteral(ast::ExprStringLiteral { value, range, .. })) =
+        call.arguments.find_argument_value("source", 0)
+    else {
+        return;
+    };
+    let source = value.to_str();
+    let result = if builtin == "eval" {
+        parse_expression(source).map(|_| ())
+    } else {
+        parse_module(source).map(|_| ())
+    };
+
+    if let Err(parse_error) = result {
+        checker.report_diagnostic(Diagnostic::new(
+            ExecOrEvalSyntaxError {
+                builtin,
+                parse_error: parse_error.error.to_string(),
+            },
+            *range,
+        ));
+    }
+}
@@ -0,0 +1,99 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::helpers::{is_docstring_stmt, Truthiness};
+use ruff_python_ast::{self as ast, Stmt};
+use ruff_python_semantic::analyze::function_type::is_stub;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `__exit__` and `__aexit__` methods that unconditionally return
+/// a truthy constant.
+///
+/// ## Why is this bad?
+/// The return value of `__exit__` (and `__aexit__`) controls whether the
+/// exception passed to it, if any, is suppressed: a truthy return value
+/// suppresses the exception, while a falsy return value (including the
+/// implicit `None` from falling off the end of the method) lets it
+/// propagate. Unconditionally returning a truthy constant, such as `True`,
+/// means the context manager will silently swallow *every* exception raised
+/// in its `with` block, which is rarely what's intended.
+///
+/// This rule only flags returns of a literal truthy constant (e.g., `True`,
+/// `1`). Returning the result of a computed expression, such as
+/// `isinstance(exc, KnownError)`, is assumed to be deliberate.
+///
+/// ## Example
+/// ```python
+/// class Suppressor:
+///     def __exit__(self, exc_type, exc_value, traceback):
+///         return True
+/// ```
+///
+/// Use instead:
+/// ```python
+/// class Suppressor:
+///     def __exit__(self, exc_type, exc_value, traceback):
+///         return exc_type is KnownError
+/// ```
+///
+/// ## References
+/// - [Python documentation: `object.__exit__`](https://docs.python.org/3/reference/datamodel.html#object.__exit__)
+#[derive(ViolationMetadata)]
+pub(crate) struct ExitSuppressesException {
+    name: String,
+}
+
+impl Violation for ExitSuppressesException {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let ExitSuppressesException { name } = self;
+        format!("`{name}` unconditionally suppresses every exception")
+    }
+}
+
+/// RUF093
+pub(crate) fn exit_suppresses_exception(checker: &Checker, function_def: &ast::StmtFunctionDef) {
+    if !matches!(function_def.name.as_str(), "__exit__" | "__aexit__") {
+        return;
+    }
+
+    if !checker.semantic().current_scope().kind.is_class() {
+        return;
+    }
+
+    if is_stub(function_def, checker.semantic()) {
+        return;
+    }
+
+    // Only flag a body that unconditionally returns a truthy constant: skip an optional leading
+    // docstring, then require the body's one remaining statement to be a bare `return <truthy>`.
+    // Anything else (an `if`, multiple statements, etc.) means the return is conditional on
+    // something, which is assumed to be deliberate.
+    let mut body = function_def.body.iter();
+    if let Some(first) = body.clone().next() {
+        if is_docstring_stmt(first) {
+            body.next();
+        }
+    }
+    let Some(Stmt::Return(ast::StmtReturn { value, .. })) = body.next() else {
+        return;
+    };
+    if body.next().is_some() {
+        return;
+    }
+    let Some(value) = value.as_deref() else {
+        return;
+    };
+
+    let truthiness = Truthiness::from_expr(value, |id| checker.semantic().has_builtin_binding(id));
+    if matches!(truthiness, Truthiness::True | Truthiness::Truthy) {
+        checker.report_diagnostic(Diagnostic::new(
+            ExitSuppressesException {
+                name: function_def.name.to_string(),
+            },
+            value.range(),
+        ));
+    }
+}
@@ -0,0 +1,224 @@
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::comparable::ComparableExpr;
+use ruff_python_ast::{self as ast, Arguments, ElifElseClause, Expr, ExprContext, Stmt};
+use ruff_text_size::{Ranged, TextRange};
+
+use crate::checkers::ast::Checker;
+use crate::fix::edits::fits;
+
+/// ## What it does
+/// Checks for `if` statements that can be replaced with `getattr` calls.
+///
+/// ## Why is this bad?
+/// `getattr(obj, "attr", default)` can be used to replace `if`-`else` blocks
+/// that assign a value to a variable in both branches, falling back to a
+/// default value if the object doesn't have the given attribute. When
+/// possible, using `getattr` is more concise and avoids a redundant
+/// `hasattr` lookup followed by a second, separate attribute access.
+///
+/// ## Example
+/// ```python
+/// if hasattr(obj, "bar"):
+///     value = obj.bar
+/// else:
+///     value = 0
+/// ```
+///
+/// Use instead:
+/// ```python
+/// value = getattr(obj, "bar", 0)
+/// ```
+///
+/// ## References
+/// - [Python documentation: `getattr`](https://docs.python.org/3/library/functions.html#getattr)
+/// - [Python documentation: `hasattr`](https://docs.python.org/3/library/functions.html#hasattr)
+#[derive(ViolationMetadata)]
+pub(crate) struct IfElseBlockInsteadOfGetattr {
+    contents: String,
+}
+
+impl Violation for IfElseBlockInsteadOfGetattr {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let IfElseBlockInsteadOfGetattr { contents } = self;
+        format!("Use `{contents}` instead of an `if` block")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        let IfElseBlockInsteadOfGetattr { contents } = self;
+        Some(format!("Replace with `{contents}`"))
+    }
+}
+
+/// RUF089
+pub(crate) fn if_else_block_instead_of_getattr(checker: &Checker, stmt_if: &ast::StmtIf) {
+    let ast::StmtIf {
+        test,
+        body,
+        elif_else_clauses,
+        ..
+    } = stmt_if;
+
+    let [body_stmt] = body.as_slice() else {
+        return;
+    };
+    let [ElifElseClause {
+        body: else_body,
+        test: None,
+        ..
+    }] = elif_else_clauses.as_slice()
+    else {
+        return;
+    };
+    let [else_body_stmt] = else_body.as_slice() else {
+        return;
+    };
+
+    let Stmt::Assign(ast::StmtAssign {
+        targets: body_var,
+        value: body_value,
+        ..
+    }) = body_stmt
+    else {
+        return;
+    };
+    let [body_var] = body_var.as_slice() else {
+        return;
+    };
+    let Stmt::Assign(ast::StmtAssign {
+        targets: orelse_var,
+        value: default_value,
+        ..
+    }) = else_body_stmt
+    else {
+        return;
+    };
+    let [orelse_var] = orelse_var.as_slice() else {
+        return;
+    };
+
+    // The assignment target must be the same in both branches.
+    if ComparableExpr::from(body_var) != ComparableExpr::from(orelse_var) {
+        return;
+    }
+
+    // The `if` test must be `hasattr(obj, "name")`, for a simple `obj`.
+    let Expr::Call(ast::ExprCall {
+        func: test_func,
+        arguments:
+            Arguments {
+                args: test_args,
+                keywords: test_keywords,
+                ..
+            },
+        ..
+    }) = &**test
+    else {
+        return;
+    };
+    let [test_obj, test_attr] = &**test_args else {
+        return;
+    };
+    if !test_keywords.is_empty() {
+        return;
+    }
+    if !test_obj.is_name_expr() {
+        return;
+    }
+    let Expr::StringLiteral(ast::ExprStringLiteral {
+        value: test_attr, ..
+    }) = test_attr
+    else {
+        return;
+    };
+
+    if !checker
+        .semantic()
+        .resolve_builtin_symbol(test_func)
+        .is_some_and(|name| name == "hasattr")
+    {
+        return;
+    }
+
+    // The `if` body must be `var = obj.name`, referencing the same `obj` and `name`.
+    let Expr::Attribute(ast::ExprAttribute {
+        value: body_obj,
+        attr: body_attr,
+        ..
+    }) = body_value.as_ref()
+    else {
+        return;
+    };
+    if ComparableExpr::from(test_obj) != ComparableExpr::from(body_obj)
+        || test_attr != body_attr.as_str()
+    {
+        return;
+    }
+
+    let attr_literal = ast::StringLiteral {
+        value: test_attr.to_string().into_boxed_str(),
+        range: TextRange::default(),
+        flags: checker.default_string_flags(),
+    };
+    let build_assign = |getattr_name: &str| {
+        let getattr_call = ast::ExprCall {
+            func: Box::new(
+                ast::ExprName {
+                    id: getattr_name.into(),
+                    ctx: ExprContext::Load,
+                    range: TextRange::default(),
+                }
+                .into(),
+            ),
+            arguments: Arguments {
+                args: Box::from([
+                    test_obj.clone(),
+                    attr_literal.clone().into(),
+                    (**default_value).clone(),
+                ]),
+                keywords: Box::from([]),
+                range: TextRange::default(),
+            },
+            range: TextRange::default(),
+        };
+        let assign = ast::StmtAssign {
+            targets: vec![(*body_var).clone()],
+            value: Box::new(getattr_call.into()),
+            range: TextRange::default(),
+        };
+        checker.generator().stmt(&assign.into())
+    };
+    let contents = build_assign("getattr");
+
+    // Don't flag if the resulting expression would exceed the maximum line length.
+    if !fits(
+        &contents,
+        stmt_if.into(),
+        checker.locator(),
+        checker.settings.pycodestyle.max_line_length,
+        checker.settings.tab_size,
+    ) {
+        return;
+    }
+
+    let mut diagnostic = Diagnostic::new(IfElseBlockInsteadOfGetattr { contents }, stmt_if.range());
+    if !checker
+        .comment_ranges()
+        .has_comments(stmt_if, checker.source())
+    {
+        diagnostic.try_set_fix(|| {
+            let (import_edit, binding) = checker.importer().get_or_import_builtin_symbol(
+                "getattr",
+                stmt_if.start(),
+                checker.semantic(),
+            )?;
+            let replacement_edit =
+                Edit::range_replacement(build_assign(&binding), stmt_if.range());
+            Ok(Fix::unsafe_edits(replacement_edit, import_edit))
+        });
+    }
+    checker.report_diagnostic(diagnostic);
+}
@@ -0,0 +1,97 @@
+use itertools::Itertools;
+
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::{self as ast, CmpOp, Expr};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `==`/`!=` comparisons between a list, tuple, or set literal on
+/// one side and a literal of a different, incompatible container type on the
+/// other.
+///
+/// ## Why is this bad?
+/// Python containers only compare equal to another container of the exact
+/// same type (with `set` and `frozenset` as the one exception, since they
+/// compare by contents regardless of which of the two types they are). A
+/// list literal can never equal a tuple literal, no matter what they
+/// contain, so comparisons like `[1, 2] == (1, 2)` always evaluate to
+/// `False`, and are usually a mistake.
+///
+/// ## Example
+/// ```python
+/// if [1, 2] == (1, 2):
+///     ...
+/// ```
+///
+/// Use instead:
+/// ```python
+/// if [1, 2] == [1, 2]:
+///     ...
+/// ```
+#[derive(ViolationMetadata)]
+pub(crate) struct IncompatibleContainerComparison {
+    /// The constant result of the comparison, i.e. `"True"` or `"False"`.
+    result: &'static str,
+}
+
+impl Violation for IncompatibleContainerComparison {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let IncompatibleContainerComparison { result } = self;
+        format!("Comparison between different container types will always evaluate to `{result}`")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ContainerKind {
+    List,
+    Tuple,
+    Set,
+}
+
+fn container_kind(expr: &Expr) -> Option<ContainerKind> {
+    match expr {
+        Expr::List(_) => Some(ContainerKind::List),
+        Expr::Tuple(_) => Some(ContainerKind::Tuple),
+        Expr::Set(_) => Some(ContainerKind::Set),
+        _ => None,
+    }
+}
+
+/// RUF079
+pub(crate) fn incompatible_container_comparison(checker: &Checker, compare: &ast::ExprCompare) {
+    for ((left, right), op) in std::iter::once(compare.left.as_ref())
+        .chain(&compare.comparators)
+        .tuple_windows()
+        .zip(&compare.ops)
+    {
+        if !matches!(op, CmpOp::Eq | CmpOp::NotEq) {
+            continue;
+        }
+
+        let (Some(left_kind), Some(right_kind)) = (container_kind(left), container_kind(right))
+        else {
+            continue;
+        };
+
+        // `set` and `frozenset` compare equal to each other by contents, but there's no
+        // literal syntax for `frozenset`, so any `List`/`Tuple`/`Set` literal mismatch here
+        // is always unequal, regardless of contents.
+        if left_kind == right_kind {
+            continue;
+        }
+
+        let result = if matches!(op, CmpOp::Eq) {
+            "False"
+        } else {
+            "True"
+        };
+        checker.report_diagnostic(Diagnostic::new(
+            IncompatibleContainerComparison { result },
+            compare.range(),
+        ));
+    }
+}
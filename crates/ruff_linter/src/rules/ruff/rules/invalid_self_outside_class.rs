@@ -0,0 +1,72 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::Expr;
+use ruff_python_semantic::ScopeKind;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for uses of `typing.Self` in annotations outside of a class
+/// definition.
+///
+/// ## Why is this bad?
+/// `Self` (PEP 673) is only meaningful as a way to refer to the enclosing
+/// class, or a subclass thereof. Using `Self` in a module-level function or
+/// any other annotation that isn't nested (directly or via further nested
+/// functions) inside a class body is not supported by type checkers and
+/// likely indicates a mistake, such as copy-pasting a method signature to a
+/// free function.
+///
+/// A `Self` reference inside a nested function that is itself defined
+/// within a method is still valid, since it still refers to the enclosing
+/// class.
+///
+/// ## Example
+/// ```python
+/// def foo(bar: Self) -> Self: ...
+/// ```
+///
+/// Use instead:
+/// ```python
+/// class Foo:
+///     def foo(self, bar: Self) -> Self: ...
+/// ```
+///
+/// ## References
+/// - [Python documentation: `typing.Self`](https://docs.python.org/3/library/typing.html#typing.Self)
+#[derive(ViolationMetadata)]
+pub(crate) struct InvalidSelfOutsideClass;
+
+impl Violation for InvalidSelfOutsideClass {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        "`Self` is only valid in annotations within a class".to_string()
+    }
+}
+
+/// RUF065
+pub(crate) fn invalid_self_outside_class(checker: &Checker, expr: &Expr) {
+    if !checker.semantic().in_annotation() {
+        return;
+    }
+
+    if !checker.semantic().match_typing_expr(expr, "Self") {
+        return;
+    }
+
+    if is_in_class_scope(checker) {
+        return;
+    }
+
+    checker.report_diagnostic(Diagnostic::new(InvalidSelfOutsideClass, expr.range()));
+}
+
+/// Returns `true` if the current scope is a class body, or is nested (directly, or via
+/// intervening function or lambda scopes) within one.
+fn is_in_class_scope(checker: &Checker) -> bool {
+    checker
+        .semantic()
+        .current_scopes()
+        .any(|scope| matches!(scope.kind, ScopeKind::Class(_)))
+}
@@ -0,0 +1,55 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::Expr;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `TypeAlias` annotations whose value is clearly not a type,
+/// such as a numeric or boolean literal.
+///
+/// ## Why is this bad?
+/// A `TypeAlias`-annotated assignment declares that the assigned name is a
+/// type alias, to be used in annotations elsewhere. Assigning it a literal
+/// value like a number, `bytes`, or a `bool` is a mistake; the resulting
+/// alias can't be used as a type and will confuse both type checkers and
+/// readers.
+///
+/// String literals are exempted, since they're commonly used as forward
+/// references to a type that isn't yet defined, and `None` is exempted,
+/// since it's conventionally accepted as shorthand for `type(None)`.
+///
+/// ## Example
+/// ```python
+/// Count: TypeAlias = 0
+/// ```
+///
+/// Use instead:
+/// ```python
+/// Count: TypeAlias = int
+/// ```
+#[derive(ViolationMetadata)]
+pub(crate) struct InvalidTypeAliasValue;
+
+impl Violation for InvalidTypeAliasValue {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        "`TypeAlias` annotation used with a value that is not a type".to_string()
+    }
+}
+
+/// RUF081
+pub(crate) fn invalid_type_alias_value(checker: &Checker, value: &Expr) {
+    let is_invalid = match value {
+        Expr::NumberLiteral(_) | Expr::BooleanLiteral(_) | Expr::BytesLiteral(_) => true,
+        Expr::List(_) | Expr::Dict(_) | Expr::Set(_) | Expr::SetComp(_) | Expr::ListComp(_)
+        | Expr::DictComp(_) => true,
+        _ => false,
+    };
+    if !is_invalid {
+        return;
+    }
+
+    checker.report_diagnostic(Diagnostic::new(InvalidTypeAliasValue, value.range()));
+}
@@ -0,0 +1,181 @@
+use ruff_diagnostics::{Applicability, Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::{self as ast, CmpOp, Expr, ExprCall};
+use ruff_python_semantic::analyze::type_inference::{PythonType, ResolvedPythonType};
+use ruff_python_semantic::SemanticModel;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `len(...)` calls that are compared against `0` or `1` to test
+/// truthiness, rather than relying on the truthiness of the sequence itself.
+///
+/// ## Why is this bad?
+/// Empty sequences are falsy in a boolean context, so `len(x) == 0` and
+/// `len(x) > 0` can be simplified to `not x` and `x`, respectively. The
+/// simplified form is more concise and idiomatic.
+///
+/// ## Example
+/// ```python
+/// if len(x) == 0:
+///     ...
+///
+/// if len(x) > 0:
+///     ...
+/// ```
+///
+/// Use instead:
+/// ```python
+/// if not x:
+///     ...
+///
+/// if x:
+///     ...
+/// ```
+///
+/// ## Fix safety
+/// This fix is marked as unsafe if the argument to `len` is not known to be a
+/// builtin sequence type (e.g., a `list`, `tuple`, `dict`, `set`, `str`, or
+/// `bytes`). For other objects, a custom `__bool__` implementation may cause
+/// the object's truthiness to diverge from `len(x) == 0`, in which case the
+/// rewrite would change the behavior of the code.
+#[derive(ViolationMetadata)]
+pub(crate) struct LenCompareToZero {
+    replacement: String,
+}
+
+impl Violation for LenCompareToZero {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Always;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        "Prefer truthiness over `len(...)` comparison to zero".to_string()
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        let LenCompareToZero { replacement } = self;
+        Some(format!("Replace with `{replacement}`"))
+    }
+}
+
+/// RUF094
+pub(crate) fn len_compare_to_zero(
+    checker: &Checker,
+    expr: &Expr,
+    left: &Expr,
+    ops: &[CmpOp],
+    comparators: &[Expr],
+) {
+    let ([op], [right]) = (ops, comparators) else {
+        return;
+    };
+
+    let semantic = checker.semantic();
+
+    let (len_call, op, literal) = if let Some(literal) = as_int_literal(right) {
+        (left, *op, literal)
+    } else if let Some(literal) = as_int_literal(left) {
+        (right, flip(*op), literal)
+    } else {
+        return;
+    };
+
+    let Some(call) = len_call.as_call_expr() else {
+        return;
+    };
+
+    if !semantic.match_builtin_expr(&call.func, "len") {
+        return;
+    }
+
+    let [argument] = &*call.arguments.args else {
+        return;
+    };
+    if !call.arguments.keywords.is_empty() {
+        return;
+    }
+
+    let negate = match (op, literal) {
+        (CmpOp::Eq, 0) | (CmpOp::Lt, 1) => true,
+        (CmpOp::NotEq, 0) | (CmpOp::Gt, 0) | (CmpOp::GtE, 1) => false,
+        _ => return,
+    };
+
+    let name = checker.generator().expr(argument);
+    let replacement = if negate {
+        format!("not {name}")
+    } else {
+        name
+    };
+
+    let mut diagnostic = Diagnostic::new(
+        LenCompareToZero {
+            replacement: replacement.clone(),
+        },
+        expr.range(),
+    );
+
+    let applicability = if is_builtin_sequence(argument, semantic) {
+        Applicability::Safe
+    } else {
+        Applicability::Unsafe
+    };
+    diagnostic.set_fix(Fix::applicable_edit(
+        Edit::range_replacement(replacement, expr.range()),
+        applicability,
+    ));
+
+    checker.report_diagnostic(diagnostic);
+}
+
+/// Return the value of `expr` if it is a small non-negative integer literal.
+fn as_int_literal(expr: &Expr) -> Option<u8> {
+    let ast::ExprNumberLiteral { value, .. } = expr.as_number_literal_expr()?;
+    let ast::Number::Int(int) = value else {
+        return None;
+    };
+    int.as_u8()
+}
+
+/// Flip a comparison operator to account for swapping the left- and right-hand sides.
+fn flip(op: CmpOp) -> CmpOp {
+    match op {
+        CmpOp::Eq => CmpOp::Eq,
+        CmpOp::NotEq => CmpOp::NotEq,
+        CmpOp::Lt => CmpOp::Gt,
+        CmpOp::LtE => CmpOp::GtE,
+        CmpOp::Gt => CmpOp::Lt,
+        CmpOp::GtE => CmpOp::LtE,
+        CmpOp::Is | CmpOp::IsNot | CmpOp::In | CmpOp::NotIn => op,
+    }
+}
+
+/// Returns `true` if `expr` is known to be an instance of a builtin sequence type, for which
+/// truthiness and `len(...)` are guaranteed to agree.
+fn is_builtin_sequence(expr: &Expr, semantic: &SemanticModel) -> bool {
+    if matches!(
+        ResolvedPythonType::from(expr),
+        ResolvedPythonType::Atom(
+            PythonType::Dict
+                | PythonType::List
+                | PythonType::Set
+                | PythonType::Tuple
+                | PythonType::String
+                | PythonType::Bytes
+        )
+    ) {
+        return true;
+    }
+
+    let Some(ExprCall { func, .. }) = expr.as_call_expr() else {
+        return false;
+    };
+
+    semantic.resolve_builtin_symbol(func).is_some_and(|func| {
+        matches!(
+            func,
+            "list" | "dict" | "set" | "frozenset" | "tuple" | "range" | "bytes" | "bytearray" | "str"
+        )
+    })
+}
@@ -0,0 +1,76 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::Decorator;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `@property`, `@classmethod`, and `@staticmethod` decorators
+/// that aren't the outermost decorator on a function.
+///
+/// ## Why is this bad?
+/// `@property`, `@classmethod`, and `@staticmethod` wrap the callable they're
+/// applied to in a descriptor object. If another decorator is applied above
+/// one of them, that outer decorator receives the descriptor rather than the
+/// original function, which usually isn't what was intended and can break at
+/// runtime (for example, the outer decorator may try to call the descriptor
+/// directly).
+///
+/// ## Example
+/// ```python
+/// class Circle:
+///     @cached
+///     @staticmethod
+///     def unit():
+///         return Circle(1)
+/// ```
+///
+/// Use instead:
+/// ```python
+/// class Circle:
+///     @staticmethod
+///     @cached
+///     def unit():
+///         return Circle(1)
+/// ```
+#[derive(ViolationMetadata)]
+pub(crate) struct MisplacedDescriptorDecorator {
+    decorator: String,
+}
+
+impl Violation for MisplacedDescriptorDecorator {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let MisplacedDescriptorDecorator { decorator } = self;
+        format!("`@{decorator}` should be the outermost decorator")
+    }
+}
+
+/// RUF078
+pub(crate) fn misplaced_descriptor_decorator(checker: &Checker, decorator_list: &[Decorator]) {
+    for (index, decorator) in decorator_list.iter().enumerate() {
+        if index == 0 {
+            continue;
+        }
+
+        let Some(qualified_name) = checker
+            .semantic()
+            .resolve_qualified_name(&decorator.expression)
+        else {
+            continue;
+        };
+
+        let decorator_name = match qualified_name.segments() {
+            ["" | "builtins", name @ ("property" | "classmethod" | "staticmethod")] => *name,
+            _ => continue,
+        };
+
+        checker.report_diagnostic(Diagnostic::new(
+            MisplacedDescriptorDecorator {
+                decorator: decorator_name.to_string(),
+            },
+            decorator.range(),
+        ));
+    }
+}
@@ -1,19 +1,33 @@
 pub(crate) use ambiguous_unicode_character::*;
+pub(crate) use assert_on_constant::*;
 pub(crate) use assert_with_print_message::*;
 pub(crate) use assignment_in_assert::*;
+pub(crate) use assignment_used_only_in_assert::*;
 pub(crate) use asyncio_dangling_task::*;
+pub(crate) use await_non_awaitable::*;
+pub(crate) use base_exception_caught::*;
 pub(crate) use class_with_mixed_type_vars::*;
 pub(crate) use collection_literal_concatenation::*;
+pub(crate) use comprehension_shadows_parameter::*;
 pub(crate) use dataclass_enum::*;
+pub(crate) use debug_guarded_block::*;
 pub(crate) use decimal_from_float_literal::*;
 pub(crate) use default_factory_kwarg::*;
+pub(crate) use delete_unassigned_attribute::*;
+pub(crate) use dict_call_with_double_star_args::*;
+pub(crate) use duplicate_decorator::*;
+pub(crate) use enum_mixin_base_order::*;
+pub(crate) use exec_or_eval_syntax_error::*;
+pub(crate) use exit_suppresses_exception::*;
 pub(crate) use explicit_f_string_type_conversion::*;
 pub(crate) use falsy_dict_get_fallback::*;
 pub(crate) use function_call_in_dataclass_default::*;
+pub(crate) use if_else_block_instead_of_getattr::*;
 pub(crate) use if_key_in_dict_del::*;
 pub(crate) use implicit_classvar_in_dataclass::*;
 pub(crate) use implicit_optional::*;
 pub(crate) use in_empty_collection::*;
+pub(crate) use incompatible_container_comparison::*;
 pub(crate) use incorrectly_parenthesized_tuple_in_subscript::*;
 pub(crate) use indented_form_feed::*;
 pub(crate) use invalid_assert_message_literal_argument::*;
@@ -21,60 +35,98 @@ pub(crate) use invalid_formatter_suppression_comment::*;
 pub(crate) use invalid_index_type::*;
 pub(crate) use invalid_pyproject_toml::*;
 pub(crate) use invalid_rule_code::*;
+pub(crate) use invalid_self_outside_class::*;
+pub(crate) use invalid_type_alias_value::*;
+pub(crate) use len_compare_to_zero::*;
 pub(crate) use map_int_version_parsing::*;
+pub(crate) use misplaced_descriptor_decorator::*;
 pub(crate) use missing_fstring_syntax::*;
 pub(crate) use mutable_class_default::*;
 pub(crate) use mutable_dataclass_default::*;
 pub(crate) use mutable_fromkeys_value::*;
+pub(crate) use mutable_partial_argument::*;
 pub(crate) use needless_else::*;
+pub(crate) use nested_ternary::*;
 pub(crate) use never_union::*;
 pub(crate) use none_not_at_end_of_union::*;
+pub(crate) use none_returning_method_assignment::*;
+pub(crate) use not_implemented_return_value::*;
+pub(crate) use overload_without_implementation::*;
+pub(crate) use parameter_reassignment::*;
 pub(crate) use parenthesize_chained_operators::*;
+pub(crate) use path_constructor_concatenation::*;
 pub(crate) use post_init_default::*;
 pub(crate) use pytest_raises_ambiguous_pattern::*;
 pub(crate) use quadratic_list_summation::*;
+pub(crate) use raise_from_none::*;
+pub(crate) use raise_in_del::*;
 pub(crate) use redirected_noqa::*;
 pub(crate) use redundant_bool_literal::*;
+pub(crate) use redundant_codec_roundtrip::*;
+pub(crate) use return_in_none_return_function::*;
+pub(crate) use returned_closed_file::*;
 pub(crate) use sort_dunder_all::*;
 pub(crate) use sort_dunder_slots::*;
 pub(crate) use starmap_zip::*;
 pub(crate) use static_key_dict_comprehension::*;
+pub(crate) use sys_exit_with_message_outside_main::*;
 #[cfg(any(feature = "test-rules", test))]
 pub(crate) use test_rules::*;
+pub(crate) use type_var_bound_and_constraints::*;
+pub(crate) use undeclared_public_name::*;
+pub(crate) use unhashable_key_or_element::*;
 pub(crate) use unnecessary_cast_to_int::*;
+pub(crate) use unnecessary_dict_get_none_default::*;
 pub(crate) use unnecessary_iterable_allocation_for_first_element::*;
+pub(crate) use unnecessary_iterable_cast_in_call::*;
 pub(crate) use unnecessary_key_check::*;
 pub(crate) use unnecessary_literal_within_deque_call::*;
 pub(crate) use unnecessary_nested_literal::*;
 pub(crate) use unnecessary_regular_expression::*;
 pub(crate) use unnecessary_round::*;
 pub(crate) use unraw_re_pattern::*;
+pub(crate) use unreachable_assert_never::*;
 pub(crate) use unsafe_markup_use::*;
 pub(crate) use unused_async::*;
 pub(crate) use unused_noqa::*;
 pub(crate) use unused_unpacked_variable::*;
 pub(crate) use used_dummy_variable::*;
 pub(crate) use useless_if_else::*;
+pub(crate) use windows_path_string_literal::*;
 pub(crate) use zip_instead_of_pairwise::*;
 
 mod ambiguous_unicode_character;
+mod assert_on_constant;
 mod assert_with_print_message;
 mod assignment_in_assert;
+mod assignment_used_only_in_assert;
 mod asyncio_dangling_task;
+mod await_non_awaitable;
+mod base_exception_caught;
 mod class_with_mixed_type_vars;
 mod collection_literal_concatenation;
+mod comprehension_shadows_parameter;
 mod confusables;
 mod dataclass_enum;
+mod debug_guarded_block;
 mod decimal_from_float_literal;
 mod default_factory_kwarg;
+mod delete_unassigned_attribute;
+mod dict_call_with_double_star_args;
+mod duplicate_decorator;
+mod enum_mixin_base_order;
+mod exec_or_eval_syntax_error;
+mod exit_suppresses_exception;
 mod explicit_f_string_type_conversion;
 mod falsy_dict_get_fallback;
 mod function_call_in_dataclass_default;
 mod helpers;
+mod if_else_block_instead_of_getattr;
 mod if_key_in_dict_del;
 mod implicit_classvar_in_dataclass;
 mod implicit_optional;
 mod in_empty_collection;
+mod incompatible_container_comparison;
 mod incorrectly_parenthesized_tuple_in_subscript;
 mod indented_form_feed;
 mod invalid_assert_message_literal_argument;
@@ -82,42 +134,66 @@ mod invalid_formatter_suppression_comment;
 mod invalid_index_type;
 mod invalid_pyproject_toml;
 mod invalid_rule_code;
+mod invalid_self_outside_class;
+mod invalid_type_alias_value;
+mod len_compare_to_zero;
 mod map_int_version_parsing;
+mod misplaced_descriptor_decorator;
 mod missing_fstring_syntax;
 mod mutable_class_default;
 mod mutable_dataclass_default;
 mod mutable_fromkeys_value;
+mod mutable_partial_argument;
 mod needless_else;
+mod nested_ternary;
 mod never_union;
 mod none_not_at_end_of_union;
+mod none_returning_method_assignment;
+mod not_implemented_return_value;
+mod overload_without_implementation;
+mod parameter_reassignment;
 mod parenthesize_chained_operators;
+mod path_constructor_concatenation;
 mod post_init_default;
 mod pytest_raises_ambiguous_pattern;
 mod quadratic_list_summation;
+mod raise_from_none;
+mod raise_in_del;
 mod redirected_noqa;
 mod redundant_bool_literal;
+mod redundant_codec_roundtrip;
+mod return_in_none_return_function;
+mod returned_closed_file;
 mod sequence_sorting;
 mod sort_dunder_all;
 mod sort_dunder_slots;
 mod starmap_zip;
 mod static_key_dict_comprehension;
 mod suppression_comment_visitor;
+mod sys_exit_with_message_outside_main;
 #[cfg(any(feature = "test-rules", test))]
 pub(crate) mod test_rules;
+mod type_var_bound_and_constraints;
+mod undeclared_public_name;
+mod unhashable_key_or_element;
 mod unnecessary_cast_to_int;
+mod unnecessary_dict_get_none_default;
 mod unnecessary_iterable_allocation_for_first_element;
+mod unnecessary_iterable_cast_in_call;
 mod unnecessary_key_check;
 mod unnecessary_literal_within_deque_call;
 mod unnecessary_nested_literal;
 mod unnecessary_regular_expression;
 mod unnecessary_round;
 mod unraw_re_pattern;
+mod unreachable_assert_never;
 mod unsafe_markup_use;
 mod unused_async;
 mod unused_noqa;
 mod unused_unpacked_variable;
 mod used_dummy_variable;
 mod useless_if_else;
+mod windows_path_string_literal;
 mod zip_instead_of_pairwise;
 
 #[derive(Clone, Copy)]
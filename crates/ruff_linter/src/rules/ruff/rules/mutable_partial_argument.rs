@@ -0,0 +1,84 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::ExprCall;
+use ruff_python_semantic::analyze::typing::is_mutable_expr;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for mutable literals (`[]`, `{}`, `set()`, etc.) passed as
+/// arguments to `functools.partial`.
+///
+/// ## Why is this bad?
+/// `functools.partial` evaluates its arguments once, when the partial
+/// object is created, and reuses them on every call to the resulting
+/// callable. As with mutable argument defaults, a mutable literal passed to
+/// `functools.partial` is thus shared across all invocations; if the
+/// callee mutates it, those mutations persist between calls.
+///
+/// ## Example
+/// ```python
+/// from functools import partial
+///
+///
+/// def add_to_list(item, some_list):
+///     some_list.append(item)
+///     return some_list
+///
+///
+/// add_zero_to_list = partial(add_to_list, some_list=[])
+/// ```
+///
+/// Use instead:
+/// ```python
+/// from functools import partial
+///
+///
+/// def add_to_list(item, some_list=None):
+///     if some_list is None:
+///         some_list = []
+///     some_list.append(item)
+///     return some_list
+///
+///
+/// add_zero_to_list = partial(add_to_list)
+/// ```
+///
+/// ## Known problems
+/// The shared object is sometimes reused intentionally, for example to
+/// accumulate state across calls. This rule can't distinguish that usage
+/// from a bug, so it's off by default.
+#[derive(ViolationMetadata)]
+pub(crate) struct MutablePartialArgument;
+
+impl Violation for MutablePartialArgument {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        "Do not pass mutable data structures as arguments to `functools.partial`".to_string()
+    }
+}
+
+/// RUF073
+pub(crate) fn mutable_partial_argument(checker: &Checker, call: &ExprCall) {
+    if !checker
+        .semantic()
+        .resolve_qualified_name(&call.func)
+        .is_some_and(|qualified_name| matches!(qualified_name.segments(), ["functools", "partial"]))
+    {
+        return;
+    }
+
+    // The first argument to `partial` is the callable being wrapped, not an
+    // argument that will be forwarded to it.
+    for argument in call.arguments.args.iter().skip(1).chain(
+        call.arguments
+            .keywords
+            .iter()
+            .map(|keyword| &keyword.value),
+    ) {
+        if is_mutable_expr(argument, checker.semantic()) {
+            checker.report_diagnostic(Diagnostic::new(MutablePartialArgument, argument.range()));
+        }
+    }
+}
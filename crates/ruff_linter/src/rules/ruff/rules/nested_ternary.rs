@@ -0,0 +1,68 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast as ast;
+use ruff_python_ast::Expr;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for ternary (conditional) expressions that are nested inside
+/// another ternary expression's body or `else` clause.
+///
+/// ## Why is this bad?
+/// Nested ternary expressions are hard to read, as the reader has to parse
+/// multiple conditions to understand the control flow. Refactoring the
+/// expression into an `if`-`elif`-`else` chain is usually clearer.
+///
+/// ## Example
+/// ```python
+/// value = a if x else (b if y else c)
+/// ```
+///
+/// Use instead:
+/// ```python
+/// if x:
+///     value = a
+/// elif y:
+///     value = b
+/// else:
+///     value = c
+/// ```
+#[derive(ViolationMetadata)]
+pub(crate) struct NestedTernary {
+    depth: u32,
+}
+
+impl Violation for NestedTernary {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let NestedTernary { depth } = self;
+        format!("Avoid nesting ternary expressions ({depth} levels deep)")
+    }
+}
+
+/// RUF090
+pub(crate) fn nested_ternary(checker: &Checker, if_expr: &ast::ExprIf) {
+    let ast::ExprIf { body, orelse, .. } = if_expr;
+
+    let nesting = chain_depth(body).max(chain_depth(orelse));
+    if nesting == 0 {
+        return;
+    }
+
+    checker.report_diagnostic(Diagnostic::new(
+        NestedTernary {
+            depth: nesting + 1,
+        },
+        if_expr.range,
+    ));
+}
+
+/// Returns the depth of the chain of ternary expressions rooted at `expr`, or `0` if `expr`
+/// is not itself a ternary expression.
+fn chain_depth(expr: &Expr) -> u32 {
+    let Expr::If(nested) = expr else {
+        return 0;
+    };
+    1 + chain_depth(&nested.body).max(chain_depth(&nested.orelse))
+}
@@ -0,0 +1,71 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::{self as ast, Expr};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for assignments where the value is a call to a method that's
+/// conventionally used for its side effect and always returns `None`, such
+/// as `list.sort()`.
+///
+/// ## Why is this bad?
+/// Methods like `list.sort()`, `list.append()`, and `dict.update()` mutate
+/// their receiver in place and return `None`. Assigning their result to a
+/// variable is almost always a mistake: the variable will be bound to
+/// `None`, and the intended, mutated value should have been read from the
+/// original receiver instead.
+///
+/// Since Ruff can't always resolve the type of the receiver, this rule
+/// keys off of a fixed set of method names that are conventionally used
+/// for their in-place effect: `append`, `clear`, `extend`, `reverse`,
+/// `sort`, and `update`. If a project defines its own methods with these
+/// names that return a meaningful value, this rule may trigger a false
+/// positive.
+///
+/// ## Example
+/// ```python
+/// sorted_numbers = numbers.sort()
+/// ```
+///
+/// Use instead:
+/// ```python
+/// numbers.sort()
+/// sorted_numbers = numbers
+/// ```
+#[derive(ViolationMetadata)]
+pub(crate) struct NoneReturningMethodAssignment {
+    method: String,
+}
+
+impl Violation for NoneReturningMethodAssignment {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let NoneReturningMethodAssignment { method } = self;
+        format!("Assigning the result of `{method}()` here always assigns `None`")
+    }
+}
+
+/// RUF080
+pub(crate) fn none_returning_method_assignment(checker: &Checker, value: &Expr) {
+    let Expr::Call(ast::ExprCall { func, .. }) = value else {
+        return;
+    };
+    let Expr::Attribute(ast::ExprAttribute { attr, .. }) = func.as_ref() else {
+        return;
+    };
+    if !matches!(
+        attr.as_str(),
+        "append" | "clear" | "extend" | "reverse" | "sort" | "update"
+    ) {
+        return;
+    }
+
+    checker.report_diagnostic(Diagnostic::new(
+        NoneReturningMethodAssignment {
+            method: attr.to_string(),
+        },
+        value.range(),
+    ));
+}
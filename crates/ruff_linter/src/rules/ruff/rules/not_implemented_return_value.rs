@@ -0,0 +1,126 @@
+use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Edit, Fix};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::{self as ast, Expr, StmtReturn};
+use ruff_python_semantic::ScopeKind;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// Special methods that may return `NotImplemented` to indicate that they don't support the
+/// operation for the given types.
+const NOT_IMPLEMENTED_METHODS: &[&str] = &[
+    "__eq__",
+    "__ne__",
+    "__lt__",
+    "__le__",
+    "__gt__",
+    "__ge__",
+    "__add__",
+    "__radd__",
+    "__sub__",
+    "__rsub__",
+    "__mul__",
+    "__rmul__",
+    "__matmul__",
+    "__rmatmul__",
+    "__truediv__",
+    "__rtruediv__",
+    "__floordiv__",
+    "__rfloordiv__",
+    "__mod__",
+    "__rmod__",
+    "__divmod__",
+    "__rdivmod__",
+    "__pow__",
+    "__rpow__",
+    "__lshift__",
+    "__rlshift__",
+    "__rshift__",
+    "__rrshift__",
+    "__and__",
+    "__rand__",
+    "__xor__",
+    "__rxor__",
+    "__or__",
+    "__ror__",
+];
+
+/// ## What it does
+/// Checks for `return NotImplementedError` (or `return NotImplementedError(...)`) in special
+/// methods that are expected to return `NotImplemented`.
+///
+/// ## Why is this bad?
+/// `NotImplementedError` is an exception class, while `NotImplemented` is a singleton value.
+/// Rich comparison and binary operator special methods (like `__eq__` or `__add__`) are expected
+/// to return `NotImplemented`, not raise or return `NotImplementedError`, to signal that the
+/// operation isn't supported for the given operands. Returning `NotImplementedError` by mistake
+/// will cause it to be treated as a truthy value instead, which is almost certainly a bug.
+///
+/// ## Example
+/// ```python
+/// class Foo:
+///     def __eq__(self, other):
+///         if not isinstance(other, Foo):
+///             return NotImplementedError
+///         return self.value == other.value
+/// ```
+///
+/// Use instead:
+/// ```python
+/// class Foo:
+///     def __eq__(self, other):
+///         if not isinstance(other, Foo):
+///             return NotImplemented
+///         return self.value == other.value
+/// ```
+///
+/// ## References
+/// - [Python documentation: `NotImplemented`](https://docs.python.org/3/library/constants.html#NotImplemented)
+/// - [Python documentation: Rich comparison methods](https://docs.python.org/3/reference/datamodel.html#object.__eq__)
+#[derive(ViolationMetadata)]
+pub(crate) struct NotImplementedReturnValue;
+
+impl AlwaysFixableViolation for NotImplementedReturnValue {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        "Use `NotImplemented` instead of `NotImplementedError` in this special method".to_string()
+    }
+
+    fn fix_title(&self) -> String {
+        "Replace with `NotImplemented`".to_string()
+    }
+}
+
+/// RUF063
+pub(crate) fn not_implemented_return_value(checker: &Checker, stmt: &StmtReturn) {
+    let Some(value) = &stmt.value else {
+        return;
+    };
+
+    let ScopeKind::Function(ast::StmtFunctionDef { name, .. }) =
+        checker.semantic().current_scope().kind
+    else {
+        return;
+    };
+    if !NOT_IMPLEMENTED_METHODS.contains(&name.as_str()) {
+        return;
+    }
+
+    let expr = match value.as_ref() {
+        Expr::Call(ast::ExprCall { func, .. }) => func.as_ref(),
+        name @ Expr::Name(_) => name,
+        _ => return,
+    };
+    if !checker
+        .semantic()
+        .match_builtin_expr(expr, "NotImplementedError")
+    {
+        return;
+    }
+
+    let diagnostic = Diagnostic::new(NotImplementedReturnValue, value.range());
+    checker.report_diagnostic(diagnostic.with_fix(Fix::safe_edit(Edit::range_replacement(
+        "NotImplemented".to_string(),
+        value.range(),
+    ))));
+}
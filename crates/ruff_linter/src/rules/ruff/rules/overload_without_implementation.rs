@@ -0,0 +1,134 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::helpers::map_subscript;
+use ruff_python_ast::{Identifier, Stmt, StmtFunctionDef};
+use ruff_python_semantic::analyze::visibility;
+use ruff_python_semantic::{Binding, Scope, ScopeKind, SemanticModel};
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for a name whose every definition in a module or class body is
+/// decorated with `@typing.overload`, without a final, non-overloaded
+/// implementation.
+///
+/// ## Why is this bad?
+/// `@overload` is used to describe the multiple call signatures of a
+/// function or method, but it's only valid when followed by a single,
+/// non-overloaded implementation of the function that all of the overloaded
+/// signatures agree with. A set of overloads with no implementation will
+/// raise `NotImplementedError` at call time (if the stub-like body is ever
+/// reached), and confuses type checkers, which expect the implementation to
+/// be present.
+///
+/// This rule doesn't apply to stub files (`.pyi`), where function bodies are
+/// always omitted, nor to `typing.Protocol` classes, whose overloaded
+/// methods are never meant to be called directly.
+///
+/// ## Example
+/// ```python
+/// from typing import overload
+///
+///
+/// @overload
+/// def foo(x: int) -> int: ...
+/// @overload
+/// def foo(x: str) -> str: ...
+/// ```
+///
+/// Use instead:
+/// ```python
+/// from typing import overload
+///
+///
+/// @overload
+/// def foo(x: int) -> int: ...
+/// @overload
+/// def foo(x: str) -> str: ...
+/// def foo(x):
+///     return x
+/// ```
+///
+/// ## References
+/// - [Python documentation: `@typing.overload`](https://docs.python.org/3/library/typing.html#typing.overload)
+#[derive(ViolationMetadata)]
+pub(crate) struct OverloadWithoutImplementation {
+    name: String,
+}
+
+impl Violation for OverloadWithoutImplementation {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let OverloadWithoutImplementation { name } = self;
+        format!("`{name}` has overload(s) but no non-overloaded implementation")
+    }
+}
+
+/// RUF070
+pub(crate) fn overload_without_implementation(checker: &Checker, scope: &Scope) {
+    if checker.source_type.is_stub() {
+        return;
+    }
+
+    if let ScopeKind::Class(class_def) = scope.kind {
+        if class_def.bases().iter().any(|base| {
+            checker
+                .semantic()
+                .match_typing_expr(map_subscript(base), "Protocol")
+        }) {
+            return;
+        }
+    }
+
+    let semantic = checker.semantic();
+
+    for (name, binding_id) in scope.bindings() {
+        let binding = semantic.binding(binding_id);
+        let Some(function_def) = as_function_def(binding, semantic) else {
+            continue;
+        };
+
+        // The most recent definition of the name is the one that would be called; if it's not
+        // an overload, then it must be the implementation (or a non-function redefinition,
+        // which is a separate problem outside the scope of this rule).
+        if !visibility::is_overload(&function_def.decorator_list, semantic) {
+            continue;
+        }
+
+        // An `@overload` paired with `@abstractmethod` is a common pattern for documenting the
+        // call signatures of a method that concrete subclasses are expected to implement.
+        if visibility::is_abstract(&function_def.decorator_list, semantic) {
+            continue;
+        }
+
+        // Require at least one other binding of the same name in this scope, so that we only
+        // flag genuine overload sets, not a single stray `@overload`.
+        if !scope
+            .shadowed_bindings(binding_id)
+            .any(|shadowed_id| as_function_def(semantic.binding(shadowed_id), semantic).is_some())
+        {
+            continue;
+        }
+
+        checker.report_diagnostic(Diagnostic::new(
+            OverloadWithoutImplementation {
+                name: (*name).to_string(),
+            },
+            function_def.identifier(),
+        ));
+    }
+}
+
+/// Return the [`StmtFunctionDef`] that `binding` refers to, if any.
+fn as_function_def<'a>(
+    binding: &Binding,
+    semantic: &SemanticModel<'a>,
+) -> Option<&'a StmtFunctionDef> {
+    if !binding.kind.is_function_definition() {
+        return None;
+    }
+    let Stmt::FunctionDef(function_def) = semantic.statement(binding.source?) else {
+        return None;
+    };
+    Some(function_def)
+}
@@ -0,0 +1,104 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::{self as ast, BoolOp, Expr};
+use ruff_python_semantic::{Binding, Scope, ScopeId, SemanticModel};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for function parameters that are reassigned within the function
+/// body.
+///
+/// ## Why is this bad?
+/// Reassigning a parameter shadows its original value, which can make the
+/// function harder to read and debug, since the parameter's value at the
+/// call site is no longer available once it's been overwritten.
+///
+/// Prefer binding the transformed value to a new name.
+///
+/// ## Example
+/// ```python
+/// def discount(price):
+///     price = price * 0.9
+///     return price
+/// ```
+///
+/// Use instead:
+/// ```python
+/// def discount(price):
+///     discounted_price = price * 0.9
+///     return discounted_price
+/// ```
+///
+/// ## Known problems
+/// This rule does not flag the common `x = x or default` idiom for
+/// applying a default value to an optional parameter.
+#[derive(ViolationMetadata)]
+pub(crate) struct ParameterReassignment {
+    name: String,
+}
+
+impl Violation for ParameterReassignment {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let ParameterReassignment { name } = self;
+        format!("Parameter `{name}` is reassigned in the function body")
+    }
+}
+
+/// RUF097
+pub(crate) fn parameter_reassignment(checker: &Checker, scope_id: ScopeId, scope: &Scope) {
+    let semantic = checker.semantic();
+
+    for (name, binding_id) in scope.bindings() {
+        for shadow in semantic.shadowed_bindings(scope_id, binding_id) {
+            let binding = &semantic.bindings[shadow.binding_id()];
+            if !binding.kind.is_assignment() {
+                continue;
+            }
+
+            let shadowed = &semantic.bindings[shadow.shadowed_id()];
+            if !shadowed.kind.is_argument() {
+                continue;
+            }
+
+            if checker.settings.dummy_variable_rgx.is_match(name) {
+                continue;
+            }
+
+            if is_default_fallback_idiom(binding, name, semantic) {
+                continue;
+            }
+
+            checker.report_diagnostic(Diagnostic::new(
+                ParameterReassignment {
+                    name: name.to_string(),
+                },
+                binding.range(),
+            ));
+        }
+    }
+}
+
+/// Returns `true` if `binding` reassigns `name` via the common `x = x or default` idiom.
+fn is_default_fallback_idiom(binding: &Binding, name: &str, semantic: &SemanticModel) -> bool {
+    let Some(stmt) = binding.statement(semantic) else {
+        return false;
+    };
+    let Some(ast::StmtAssign { targets, value, .. }) = stmt.as_assign_stmt() else {
+        return false;
+    };
+    let [Expr::Name(target)] = targets.as_slice() else {
+        return false;
+    };
+    if target.id != name {
+        return false;
+    }
+
+    matches!(
+        value.as_ref(),
+        Expr::BoolOp(ast::ExprBoolOp { op: BoolOp::Or, values, .. })
+            if matches!(values.first(), Some(Expr::Name(first)) if first.id == name)
+    )
+}
@@ -0,0 +1,69 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::{Expr, ExprBinOp, ExprCall, Operator};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for string concatenation (`+`) where one of the operands is a
+/// `pathlib.Path` constructor call.
+///
+/// ## Why is this bad?
+/// `pathlib.Path` overloads the `/` operator for joining path segments.
+/// Concatenating a `Path` with a string using `+` bypasses this API, is
+/// harder to read, and doesn't insert the platform-specific separator
+/// automatically.
+///
+/// ## Example
+/// ```python
+/// path = Path("/tmp") + "/" + name
+/// ```
+///
+/// Use instead:
+/// ```python
+/// path = Path("/tmp") / name
+/// ```
+///
+/// ## Known problems
+/// This rule is syntax-based: it only flags concatenations where a
+/// `Path(...)` call is directly visible as one of the operands. It won't
+/// catch cases where the `Path` value is stored in a variable before being
+/// concatenated, since that would require type inference.
+#[derive(ViolationMetadata)]
+pub(crate) struct PathConstructorConcatenation;
+
+impl Violation for PathConstructorConcatenation {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        "Use `/` to join `pathlib.Path` objects instead of `+`".to_string()
+    }
+}
+
+/// RUF064
+pub(crate) fn path_constructor_concatenation(checker: &Checker, expr: &Expr) {
+    let Expr::BinOp(ExprBinOp {
+        left,
+        op: Operator::Add,
+        right,
+        ..
+    }) = expr
+    else {
+        return;
+    };
+
+    if is_path_constructor_call(left, checker) || is_path_constructor_call(right, checker) {
+        checker.report_diagnostic(Diagnostic::new(PathConstructorConcatenation, expr.range()));
+    }
+}
+
+/// Returns `true` if `expr` is a direct call to `pathlib.Path(...)`.
+fn is_path_constructor_call(expr: &Expr, checker: &Checker) -> bool {
+    let Expr::Call(ExprCall { func, .. }) = expr else {
+        return false;
+    };
+    checker
+        .semantic()
+        .resolve_qualified_name(func)
+        .is_some_and(|qualified_name| matches!(qualified_name.segments(), ["pathlib", "Path"]))
+}
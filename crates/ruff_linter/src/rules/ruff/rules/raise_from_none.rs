@@ -0,0 +1,64 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast as ast;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `raise ... from None` statements.
+///
+/// ## Why is this bad?
+/// `raise ... from None` suppresses exception chaining, discarding the
+/// original exception's traceback and context. While this is sometimes
+/// desirable (for example, to hide an implementation detail from the
+/// caller of a public API), it can also obscure the root cause of a
+/// failure, making the resulting traceback harder to debug.
+///
+/// If you're re-raising an exception from within an `except` block and
+/// intended to preserve the original context, use `from err` (where `err`
+/// is the caught exception) instead of `from None`.
+///
+/// This rule is disabled by default because suppressing the exception
+/// context is a deliberate, common choice in library code; enable it, and
+/// use `per-file-ignores` to allow `from None` in the parts of your
+/// codebase where it's intentional.
+///
+/// ## Example
+/// ```python
+/// try:
+///     ...
+/// except ValueError as err:
+///     raise RuntimeError("...") from None
+/// ```
+///
+/// Use instead:
+/// ```python
+/// try:
+///     ...
+/// except ValueError as err:
+///     raise RuntimeError("...") from err
+/// ```
+///
+/// ## References
+/// - [Python documentation: `raise` statement](https://docs.python.org/3/reference/simple_stmts.html#the-raise-statement)
+#[derive(ViolationMetadata)]
+pub(crate) struct RaiseFromNone;
+
+impl Violation for RaiseFromNone {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        "`raise ... from None` suppresses exception chaining, hiding the original cause".to_string()
+    }
+}
+
+/// RUF096
+pub(crate) fn raise_from_none(checker: &Checker, raise: &ast::StmtRaise) {
+    let Some(cause) = &raise.cause else {
+        return;
+    };
+
+    if cause.is_none_literal_expr() {
+        checker.report_diagnostic(Diagnostic::new(RaiseFromNone, raise.range()));
+    }
+}
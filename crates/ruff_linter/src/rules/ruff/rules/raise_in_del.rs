@@ -0,0 +1,55 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast as ast;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+use crate::rules::pylint::helpers::in_dunder_method;
+
+/// ## What it does
+/// Checks for `raise` statements in `__del__` methods.
+///
+/// ## Why is this bad?
+/// The interpreter calls `__del__` methods on a best-effort basis when an
+/// object is garbage collected, and it can't propagate an exception raised
+/// there back to any caller. Instead, the exception is written to `stderr`
+/// (as an "Exception ignored in" message) and otherwise discarded, so
+/// raising in `__del__` can't signal a failure to the rest of the program.
+///
+/// ## Example
+/// ```python
+/// class Resource:
+///     def __del__(self):
+///         if not self.closed:
+///             raise RuntimeError("resource was never closed")
+/// ```
+///
+/// Use instead:
+/// ```python
+/// import logging
+///
+///
+/// class Resource:
+///     def __del__(self):
+///         if not self.closed:
+///             logging.warning("resource was never closed")
+/// ```
+///
+/// ## References
+/// - [Python documentation: `object.__del__`](https://docs.python.org/3/reference/datamodel.html#object.__del__)
+#[derive(ViolationMetadata)]
+pub(crate) struct RaiseInDel;
+
+impl Violation for RaiseInDel {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        "`raise` in `__del__` has no effect, as exceptions raised there are ignored".to_string()
+    }
+}
+
+/// RUF069
+pub(crate) fn raise_in_del(checker: &Checker, raise: &ast::StmtRaise) {
+    if in_dunder_method("__del__", checker.semantic(), checker.settings) {
+        checker.report_diagnostic(Diagnostic::new(RaiseInDel, raise.range()));
+    }
+}
@@ -0,0 +1,113 @@
+use ruff_diagnostics::{Diagnostic, Edit, Fix, FixAvailability, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::{self as ast, Expr, ExprCall};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for chained `.encode().decode()` or `.decode().encode()` calls.
+///
+/// ## Why is this bad?
+/// Encoding a string and immediately decoding it (or vice versa) is a
+/// round-trip that, when both calls use the same codec, simply returns a
+/// copy of the original value. This is usually leftover from a refactor and
+/// can be removed.
+///
+/// ## Example
+/// ```python
+/// s.encode().decode()
+/// ```
+///
+/// Use instead:
+/// ```python
+/// s
+/// ```
+///
+/// ## Fix safety
+/// This rule can't determine the type of the receiver, so it doesn't know
+/// whether `.encode()`/`.decode()` actually resolve to the `str`/`bytes`
+/// methods being targeted here. The fix is always marked as unsafe, and is
+/// only offered when both calls use the same (or both default) encoding.
+#[derive(ViolationMetadata)]
+pub(crate) struct RedundantCodecRoundtrip {
+    outer: &'static str,
+    inner: &'static str,
+}
+
+impl Violation for RedundantCodecRoundtrip {
+    const FIX_AVAILABILITY: FixAvailability = FixAvailability::Sometimes;
+
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let RedundantCodecRoundtrip { outer, inner } = self;
+        format!("Avoid unnecessary `{inner}().{outer}()` round-trip")
+    }
+
+    fn fix_title(&self) -> Option<String> {
+        Some("Remove the round-trip".to_string())
+    }
+}
+
+/// RUF074
+pub(crate) fn redundant_codec_roundtrip(checker: &Checker, call: &ExprCall) {
+    let Expr::Attribute(ast::ExprAttribute {
+        value: receiver,
+        attr: outer_attr,
+        ..
+    }) = call.func.as_ref()
+    else {
+        return;
+    };
+
+    let outer = match outer_attr.as_str() {
+        "decode" => "decode",
+        "encode" => "encode",
+        _ => return,
+    };
+    let inner = if outer == "decode" { "encode" } else { "decode" };
+
+    let Expr::Call(inner_call) = receiver.as_ref() else {
+        return;
+    };
+    let Expr::Attribute(ast::ExprAttribute {
+        value: base,
+        attr: inner_attr,
+        ..
+    }) = inner_call.func.as_ref()
+    else {
+        return;
+    };
+    if inner_attr != inner {
+        return;
+    }
+
+    let mut diagnostic = Diagnostic::new(RedundantCodecRoundtrip { outer, inner }, call.range());
+
+    if let (Some(outer_encoding), Some(inner_encoding)) = (
+        encoding_argument(&call.arguments),
+        encoding_argument(&inner_call.arguments),
+    ) {
+        if outer_encoding == inner_encoding {
+            diagnostic.set_fix(Fix::unsafe_edit(Edit::range_replacement(
+                checker.locator().slice(base.range()).to_string(),
+                call.range(),
+            )));
+        }
+    }
+
+    checker.report_diagnostic(diagnostic);
+}
+
+/// Return the explicit encoding argument of an `.encode()`/`.decode()` call, if any, or `Some(None)`
+/// if the call relies on the default encoding. Returns `None` if the encoding can't be determined
+/// statically (e.g., it's not a string literal).
+fn encoding_argument(arguments: &ast::Arguments) -> Option<Option<&str>> {
+    let Some(encoding) = arguments.find_argument_value("encoding", 0) else {
+        return Some(None);
+    };
+    match encoding {
+        Expr::StringLiteral(ast::ExprStringLiteral { value, .. }) => Some(Some(value.to_str())),
+        _ => None,
+    }
+}
@@ -0,0 +1,78 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::helpers::ReturnStatementVisitor;
+use ruff_python_ast::visitor::Visitor;
+use ruff_python_ast::{self as ast, Expr};
+use ruff_python_semantic::analyze::function_type::is_stub;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `return` statements with a non-`None` value in a function
+/// annotated as returning `None`.
+///
+/// ## Why is this bad?
+/// A function annotated `-> None` documents that it has no meaningful
+/// return value. Returning a value that contradicts the annotation is
+/// likely a mistake, either in the annotation or in the `return`
+/// statement itself.
+///
+/// ## Example
+/// ```python
+/// def compute(x: int) -> None:
+///     return x + 1
+/// ```
+///
+/// Use instead:
+/// ```python
+/// def compute(x: int) -> int:
+///     return x + 1
+/// ```
+///
+/// ## References
+/// - [Python documentation: The `None` type](https://docs.python.org/3/library/constants.html#None)
+#[derive(ViolationMetadata)]
+pub(crate) struct ReturnInNoneReturnFunction;
+
+impl Violation for ReturnInNoneReturnFunction {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        "`return` with a value in a function annotated as returning `None`".to_string()
+    }
+}
+
+/// RUF085
+pub(crate) fn return_in_none_return_function(
+    checker: &Checker,
+    function_def: &ast::StmtFunctionDef,
+) {
+    let Some(returns) = function_def.returns.as_deref() else {
+        return;
+    };
+    if !returns.is_none_literal_expr() {
+        return;
+    }
+
+    if is_stub(function_def, checker.semantic()) {
+        return;
+    }
+
+    let return_stmts = {
+        let mut visitor = ReturnStatementVisitor::default();
+        visitor.visit_body(&function_def.body);
+        visitor.returns
+    };
+
+    for stmt in return_stmts {
+        let Some(value) = stmt.value.as_deref() else {
+            // Bare `return` is exempt.
+            continue;
+        };
+        if matches!(value, Expr::NoneLiteral(_)) {
+            // Explicit `return None` is exempt.
+            continue;
+        }
+        checker.report_diagnostic(Diagnostic::new(ReturnInNoneReturnFunction, value.range()));
+    }
+}
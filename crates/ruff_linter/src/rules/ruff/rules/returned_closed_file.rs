@@ -0,0 +1,82 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::helpers::ReturnStatementVisitor;
+use ruff_python_ast::visitor::Visitor;
+use ruff_python_ast::{self as ast, Expr};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `return` statements, inside a `with open(...) as f:` block,
+/// that return the file handle itself.
+///
+/// ## Why is this bad?
+/// A `with` block closes its context manager on exit, including when that
+/// exit is triggered by a `return` from inside the block. Returning the file
+/// handle bound by the `with` therefore hands the caller a file that's
+/// already closed, which will raise a `ValueError` the next time it's used.
+///
+/// ## Example
+/// ```python
+/// def get_file(path):
+///     with open(path) as f:
+///         return f
+/// ```
+///
+/// Use instead:
+/// ```python
+/// def get_file(path):
+///     return open(path)
+/// ```
+#[derive(ViolationMetadata)]
+pub(crate) struct ReturnedClosedFile {
+    name: String,
+}
+
+impl Violation for ReturnedClosedFile {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let ReturnedClosedFile { name } = self;
+        format!("Returning `{name}` here returns an already-closed file handle")
+    }
+}
+
+/// RUF076
+pub(crate) fn returned_closed_file(checker: &Checker, with_stmt: &ast::StmtWith) {
+    if with_stmt.is_async {
+        return;
+    }
+
+    for item in &with_stmt.items {
+        let Some(ast::ExprCall { func, .. }) = item.context_expr.as_call_expr() else {
+            continue;
+        };
+        if !checker.semantic().match_builtin_expr(func, "open") {
+            continue;
+        }
+        let Some(Expr::Name(ast::ExprName { id, .. })) = item.optional_vars.as_deref() else {
+            continue;
+        };
+
+        let mut visitor = ReturnStatementVisitor::default();
+        for stmt in &with_stmt.body {
+            visitor.visit_stmt(stmt);
+        }
+
+        for stmt_return in &visitor.returns {
+            let Some(Expr::Name(ast::ExprName { id: returned, .. })) = stmt_return.value.as_deref()
+            else {
+                continue;
+            };
+            if returned == id {
+                checker.report_diagnostic(Diagnostic::new(
+                    ReturnedClosedFile {
+                        name: id.to_string(),
+                    },
+                    stmt_return.range(),
+                ));
+            }
+        }
+    }
+}
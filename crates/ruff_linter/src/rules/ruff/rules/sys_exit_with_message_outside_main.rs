@@ -0,0 +1,96 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::{self as ast, Expr, ExprCall, Stmt};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `sys.exit()` calls with a string message that occur outside of
+/// an `if __name__ == "__main__":` block.
+///
+/// ## Why is this bad?
+/// `sys.exit("some message")` prints the message to `stderr` and exits with
+/// status code `1`. That's a reasonable way for a script's entry point to
+/// fail, but library code that's imported by other modules shouldn't decide
+/// to terminate the interpreter on its caller's behalf. Raising an exception
+/// instead lets the caller decide how to handle the failure.
+///
+/// `sys.exit()` calls made directly within an `if __name__ == "__main__":`
+/// block are exempt, since such blocks typically guard a script's
+/// command-line entry point rather than library code.
+///
+/// ## Example
+/// ```python
+/// def parse_config(path):
+///     if not path.exists():
+///         sys.exit(f"Config file not found: {path}")
+/// ```
+///
+/// Use instead:
+/// ```python
+/// def parse_config(path):
+///     if not path.exists():
+///         raise FileNotFoundError(f"Config file not found: {path}")
+/// ```
+#[derive(ViolationMetadata)]
+pub(crate) struct SysExitWithMessageOutsideMain;
+
+impl Violation for SysExitWithMessageOutsideMain {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        "`sys.exit()` called with a message outside of an `if __name__ == \"__main__\":` block"
+            .to_string()
+    }
+}
+
+/// RUF083
+pub(crate) fn sys_exit_with_message_outside_main(checker: &Checker, call: &ExprCall) {
+    let semantic = checker.semantic();
+
+    let Some(qualified_name) = semantic.resolve_qualified_name(&call.func) else {
+        return;
+    };
+    if qualified_name.segments() != ["sys", "exit"] {
+        return;
+    }
+
+    let Some(arg) = call.arguments.args.first() else {
+        return;
+    };
+    if !matches!(arg, Expr::StringLiteral(_) | Expr::FString(_)) {
+        return;
+    }
+
+    if semantic
+        .current_statements()
+        .any(|stmt| matches!(stmt, Stmt::If(stmt_if) if is_main_check(&stmt_if.test)))
+    {
+        return;
+    }
+
+    checker.report_diagnostic(Diagnostic::new(
+        SysExitWithMessageOutsideMain,
+        call.range(),
+    ));
+}
+
+/// Returns `true` if an expression is an `if __name__ == "__main__":` check.
+fn is_main_check(expr: &Expr) -> bool {
+    let Expr::Compare(ast::ExprCompare {
+        left, comparators, ..
+    }) = expr
+    else {
+        return false;
+    };
+    let Expr::Name(ast::ExprName { id, .. }) = left.as_ref() else {
+        return false;
+    };
+    if id != "__name__" {
+        return false;
+    }
+    let [Expr::StringLiteral(ast::ExprStringLiteral { value, .. })] = &**comparators else {
+        return false;
+    };
+    value == "__main__"
+}
@@ -0,0 +1,100 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::{self as ast, Expr};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `TypeVar` definitions that specify both a `bound` and one or
+/// more constraints.
+///
+/// ## Why is this bad?
+/// `TypeVar` accepts either a `bound` or a set of constraints, but not both;
+/// providing both raises a `TypeError` at runtime. A `bound` restricts the
+/// type variable to a single type (or its subtypes), while constraints
+/// restrict it to one of a fixed set of unrelated types.
+///
+/// ## Example
+/// ```python
+/// from typing import TypeVar
+///
+/// T = TypeVar("T", int, str, bound=int)
+/// ```
+///
+/// Use instead:
+/// ```python
+/// from typing import TypeVar
+///
+/// T = TypeVar("T", bound=int)
+/// ```
+///
+/// ## References
+/// - [Python documentation: `typing.TypeVar`](https://docs.python.org/3/library/typing.html#typing.TypeVar)
+#[derive(ViolationMetadata)]
+pub(crate) struct TypeVarBoundAndConstraints {
+    param_name: Option<String>,
+}
+
+impl Violation for TypeVarBoundAndConstraints {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let TypeVarBoundAndConstraints { param_name } = self;
+        match param_name {
+            None => "`TypeVar` cannot have both `bound` and constraints".to_string(),
+            Some(param_name) => {
+                format!("`TypeVar` \"{param_name}\" cannot have both `bound` and constraints")
+            }
+        }
+    }
+}
+
+/// RUF072
+pub(crate) fn type_var_bound_and_constraints(checker: &Checker, value: &Expr) {
+    // If the typing modules were never imported, we'll never match below.
+    if !checker.semantic().seen_typing() {
+        return;
+    }
+
+    let Expr::Call(ast::ExprCall {
+        func, arguments, ..
+    }) = value
+    else {
+        return;
+    };
+
+    if !checker
+        .semantic()
+        .resolve_qualified_name(func)
+        .is_some_and(|qualified_name| {
+            checker
+                .semantic()
+                .match_typing_qualified_name(&qualified_name, "TypeVar")
+        })
+    {
+        return;
+    }
+
+    // `TypeVar("T", int, str)` has constraints beyond the name in the first
+    // positional argument.
+    if arguments.args.len() < 2 {
+        return;
+    }
+
+    let Some(bound) = arguments.find_keyword("bound") else {
+        return;
+    };
+
+    let param_name = arguments.args.first().and_then(|name| {
+        if let Expr::StringLiteral(ast::ExprStringLiteral { value, .. }) = name {
+            Some(value.to_str().to_string())
+        } else {
+            None
+        }
+    });
+
+    checker.report_diagnostic(Diagnostic::new(
+        TypeVarBoundAndConstraints { param_name },
+        bound.range(),
+    ));
+}
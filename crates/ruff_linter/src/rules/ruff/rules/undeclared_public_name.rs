@@ -0,0 +1,85 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_semantic::BindingKind;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for module-level functions, classes, and assignments whose names
+/// don't start with an underscore but are missing from the module's
+/// `__all__` list, when `__all__` is present.
+///
+/// ## Why is this bad?
+/// When a module defines `__all__`, it's making an explicit statement about
+/// its public API: only the names listed there are exported by
+/// `from module import *`, and are generally understood to be the module's
+/// supported interface. A public-looking name (one that doesn't start with
+/// an underscore) that's left out of `__all__` is easy to miss, and callers
+/// may end up relying on it anyway, defeating the purpose of declaring
+/// `__all__` in the first place.
+///
+/// ## Example
+/// ```python
+/// __all__ = ["foo"]
+///
+///
+/// def foo(): ...
+///
+///
+/// def bar(): ...
+/// ```
+///
+/// Use instead:
+/// ```python
+/// __all__ = ["foo", "bar"]
+///
+///
+/// def foo(): ...
+///
+///
+/// def bar(): ...
+/// ```
+#[derive(ViolationMetadata)]
+pub(crate) struct UndeclaredPublicName {
+    name: String,
+}
+
+impl Violation for UndeclaredPublicName {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let UndeclaredPublicName { name } = self;
+        format!("Public name `{name}` is not included in `__all__`")
+    }
+}
+
+/// RUF077
+pub(crate) fn undeclared_public_names(checker: &Checker, exported: &[&str]) {
+    let semantic = checker.semantic();
+
+    for (name, binding_id) in semantic.global_scope().bindings() {
+        if name.starts_with('_') {
+            continue;
+        }
+        if exported.contains(&name) {
+            continue;
+        }
+
+        let binding = semantic.binding(binding_id);
+        if !matches!(
+            binding.kind,
+            BindingKind::FunctionDefinition(_)
+                | BindingKind::ClassDefinition(_)
+                | BindingKind::Assignment
+        ) {
+            continue;
+        }
+
+        checker.report_diagnostic(Diagnostic::new(
+            UndeclaredPublicName {
+                name: name.to_string(),
+            },
+            binding.range(),
+        ));
+    }
+}
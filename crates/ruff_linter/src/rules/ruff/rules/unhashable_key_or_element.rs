@@ -0,0 +1,78 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::{self as ast, Expr};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for `dict` literals with unhashable keys and `set` literals with
+/// unhashable elements.
+///
+/// ## Why is this bad?
+/// Using a `list`, `dict`, or `set` literal as a `dict` key or `set` element
+/// raises a `TypeError` at runtime, since these types are unhashable. A
+/// `tuple` literal is unhashable if it contains an unhashable element.
+///
+/// ## Example
+/// ```python
+/// {[1, 2]: "value"}
+/// {[1, 2]}
+/// ```
+#[derive(ViolationMetadata)]
+pub(crate) struct UnhashableKeyOrElement {
+    kind: Kind,
+}
+
+impl Violation for UnhashableKeyOrElement {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        match self.kind {
+            Kind::DictKey => "Dict key is unhashable".to_string(),
+            Kind::SetElement => "Set element is unhashable".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    DictKey,
+    SetElement,
+}
+
+/// RUF095
+pub(crate) fn unhashable_dict_key(checker: &Checker, dict: &ast::ExprDict) {
+    for key in dict.iter_keys().flatten() {
+        if is_unhashable_expr(key) {
+            checker.report_diagnostic(Diagnostic::new(
+                UnhashableKeyOrElement {
+                    kind: Kind::DictKey,
+                },
+                key.range(),
+            ));
+        }
+    }
+}
+
+/// RUF095
+pub(crate) fn unhashable_set_element(checker: &Checker, set: &ast::ExprSet) {
+    for element in &set.elts {
+        if is_unhashable_expr(element) {
+            checker.report_diagnostic(Diagnostic::new(
+                UnhashableKeyOrElement {
+                    kind: Kind::SetElement,
+                },
+                element.range(),
+            ));
+        }
+    }
+}
+
+/// Returns `true` if `expr` is known, statically, to be unhashable.
+fn is_unhashable_expr(expr: &Expr) -> bool {
+    match expr {
+        Expr::List(_) | Expr::Dict(_) | Expr::Set(_) => true,
+        Expr::Tuple(ast::ExprTuple { elts, .. }) => elts.iter().any(is_unhashable_expr),
+        _ => false,
+    }
+}
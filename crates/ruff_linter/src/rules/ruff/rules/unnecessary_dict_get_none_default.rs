@@ -0,0 +1,103 @@
+use crate::checkers::ast::Checker;
+use crate::fix::edits::{remove_argument, Parentheses};
+use ruff_diagnostics::{AlwaysFixableViolation, Applicability, Diagnostic, Fix};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::{Expr, ExprAttribute};
+use ruff_python_semantic::analyze::typing;
+use ruff_text_size::Ranged;
+
+/// ## What it does
+/// Checks for `dict.get(key, None)` calls, where the explicit `None` default
+/// is redundant.
+///
+/// ## Why is this bad?
+/// `dict.get(key)` already returns `None` if `key` is missing, so passing
+/// `None` as the default argument is unnecessary and only adds noise.
+///
+/// ## Example
+///
+/// ```python
+/// value = dct.get(key, None)
+/// ```
+///
+/// Use instead:
+///
+/// ```python
+/// value = dct.get(key)
+/// ```
+///
+/// ## Fix safety
+/// This rule's fix is marked as safe, unless the `dict.get()` call contains comments between arguments.
+#[derive(ViolationMetadata)]
+pub(crate) struct UnnecessaryDictGetNoneDefault;
+
+impl AlwaysFixableViolation for UnnecessaryDictGetNoneDefault {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        "Unnecessary `None` default provided to `dict.get()`".to_string()
+    }
+
+    fn fix_title(&self) -> String {
+        "Remove `None` default from `dict.get()`".to_string()
+    }
+}
+
+/// RUF071
+pub(crate) fn unnecessary_dict_get_none_default(checker: &Checker, expr: &Expr) {
+    let semantic = checker.semantic();
+
+    let Expr::Call(call) = expr else {
+        return;
+    };
+
+    // Check if the function being called is an attribute (e.g. `dict.get`)
+    let Expr::Attribute(ExprAttribute { value, attr, .. }) = &*call.func else {
+        return;
+    };
+
+    // Ensure the method called is `get`
+    if attr != "get" {
+        return;
+    }
+
+    // Check if the object is a dictionary using the semantic model
+    if !value
+        .as_name_expr()
+        .is_some_and(|name| typing::is_known_to_be_of_type_dict(semantic, name))
+    {
+        return;
+    }
+
+    // Get the default argument
+    let Some(default_arg) = call.arguments.find_argument("default", 1) else {
+        return;
+    };
+
+    // Check if the default is a `None` literal
+    if !default_arg.value().is_none_literal_expr() {
+        return;
+    }
+
+    let mut diagnostic = Diagnostic::new(UnnecessaryDictGetNoneDefault, default_arg.range());
+
+    let comment_ranges = checker.comment_ranges();
+
+    // Determine applicability based on the presence of comments
+    let applicability = if comment_ranges.intersects(call.arguments.range()) {
+        Applicability::Unsafe
+    } else {
+        Applicability::Safe
+    };
+
+    diagnostic.try_set_fix(|| {
+        remove_argument(
+            &default_arg,
+            &call.arguments,
+            Parentheses::Preserve,
+            checker.locator().contents(),
+        )
+        .map(|edit| Fix::applicable_edit(edit, applicability))
+    });
+
+    checker.report_diagnostic(diagnostic);
+}
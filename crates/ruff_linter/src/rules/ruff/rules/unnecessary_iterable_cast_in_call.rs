@@ -0,0 +1,112 @@
+use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Fix};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::{self as ast, Expr};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+use crate::rules::flake8_comprehensions::fixes::fix_unnecessary_double_cast_or_process;
+
+/// ## What it does
+/// Checks for `list()` or `tuple()` calls that are redundantly passed to
+/// `min()`, `max()`, or `sum()`.
+///
+/// ## Why is this bad?
+/// `min()`, `max()`, and `sum()` all accept any iterable, not just sequences,
+/// so wrapping the argument in `list()` or `tuple()` first is unnecessary and
+/// merely allocates a throwaway copy of the iterable.
+///
+/// ## Example
+/// ```python
+/// min(list(x))
+/// max(tuple(x))
+/// sum(list(x))
+/// ```
+///
+/// Use instead:
+/// ```python
+/// min(x)
+/// max(x)
+/// sum(x)
+/// ```
+///
+/// ## Known problems
+/// Unlike `min()`, `max()`, and `sum()`, `reversed()` requires its argument
+/// to be a sequence (or otherwise implement `__reversed__`); it does not
+/// accept arbitrary iterables. As such, this rule does not flag
+/// `reversed(list(x))`, since removing the `list()` call could turn working
+/// code into a runtime error if `x` isn't already a sequence.
+///
+/// ## Fix safety
+/// This rule's fix is marked as safe, as `list()` and `tuple()` preserve
+/// both the order and the multiset of elements of the iterable they wrap, so
+/// removing them does not change the result of `min()`, `max()`, or `sum()`.
+#[derive(ViolationMetadata)]
+pub(crate) struct UnnecessaryIterableCastInCall {
+    inner: String,
+    outer: String,
+}
+
+impl AlwaysFixableViolation for UnnecessaryIterableCastInCall {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let UnnecessaryIterableCastInCall { inner, outer } = self;
+        format!("Unnecessary `{inner}()` call within `{outer}()`")
+    }
+
+    fn fix_title(&self) -> String {
+        let UnnecessaryIterableCastInCall { inner, .. } = self;
+        format!("Remove the inner `{inner}()` call")
+    }
+}
+
+/// RUF088
+pub(crate) fn unnecessary_iterable_cast_in_call(
+    checker: &Checker,
+    expr: &Expr,
+    func: &Expr,
+    args: &[Expr],
+) {
+    let Some(arg) = args.first() else {
+        return;
+    };
+    let Expr::Call(ast::ExprCall {
+        func: inner_func,
+        arguments: inner_arguments,
+        ..
+    }) = arg
+    else {
+        return;
+    };
+    // Only handle the single-argument, no-keyword form (e.g., not `list(x, y)`,
+    // which isn't valid anyway, or `sorted(x, key=...)`).
+    if inner_arguments.args.len() != 1 || !inner_arguments.keywords.is_empty() {
+        return;
+    }
+
+    let semantic = checker.semantic();
+    let Some(outer_func_name) = semantic.resolve_builtin_symbol(func) else {
+        return;
+    };
+    if !matches!(outer_func_name, "min" | "max" | "sum") {
+        return;
+    }
+    let Some(inner_func_name) = semantic.resolve_builtin_symbol(inner_func) else {
+        return;
+    };
+    if !matches!(inner_func_name, "list" | "tuple") {
+        return;
+    }
+
+    let mut diagnostic = Diagnostic::new(
+        UnnecessaryIterableCastInCall {
+            inner: inner_func_name.to_string(),
+            outer: outer_func_name.to_string(),
+        },
+        expr.range(),
+    );
+    diagnostic.try_set_fix(|| {
+        fix_unnecessary_double_cast_or_process(expr, checker.locator(), checker.stylist())
+            .map(Fix::safe_edit)
+    });
+    checker.report_diagnostic(diagnostic);
+}
@@ -0,0 +1,78 @@
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::{Expr, Stmt, StmtExpr};
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for statements following a call to `typing.assert_never` within
+/// the same block.
+///
+/// ## Why is this bad?
+/// `assert_never` (PEP 647) tells the type checker that a branch is
+/// unreachable at runtime; if it is ever actually reached, `assert_never`
+/// raises an exception. As a result, any statement placed after a call to
+/// `assert_never` in the same block is dead code: it can never execute.
+///
+/// ## Example
+/// ```python
+/// match command:
+///     case Command.START:
+///         start()
+///     case _:
+///         assert_never(command)
+///         print("unreachable")
+/// ```
+///
+/// Use instead:
+/// ```python
+/// match command:
+///     case Command.START:
+///         start()
+///     case _:
+///         assert_never(command)
+/// ```
+///
+/// ## References
+/// - [Python documentation: `typing.assert_never`](https://docs.python.org/3/library/typing.html#typing.assert_never)
+#[derive(ViolationMetadata)]
+pub(crate) struct UnreachableAssertNever;
+
+impl Violation for UnreachableAssertNever {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        "Statement is unreachable, as it follows a call to `assert_never`".to_string()
+    }
+}
+
+/// RUF066
+pub(crate) fn unreachable_assert_never(checker: &Checker, suite: &[Stmt]) {
+    let Some(assert_never_index) = suite.iter().position(|stmt| is_assert_never_call(stmt, checker))
+    else {
+        return;
+    };
+
+    // Anything after the `assert_never` call in this block can never execute.
+    if let Some(unreachable) = suite.get(assert_never_index + 1) {
+        checker.report_diagnostic(Diagnostic::new(UnreachableAssertNever, unreachable.range()));
+    }
+}
+
+/// Returns `true` if `stmt` is an expression statement that calls `typing.assert_never`.
+fn is_assert_never_call(stmt: &Stmt, checker: &Checker) -> bool {
+    let Stmt::Expr(StmtExpr { value, .. }) = stmt else {
+        return false;
+    };
+    let Expr::Call(call) = value.as_ref() else {
+        return false;
+    };
+    checker
+        .semantic()
+        .resolve_qualified_name(&call.func)
+        .is_some_and(|qualified_name| {
+            checker
+                .semantic()
+                .match_typing_qualified_name(&qualified_name, "assert_never")
+        })
+}
@@ -18,6 +18,16 @@ pub(crate) struct UnusedCodes {
 /// A `noqa` directive that no longer matches any diagnostic violations is
 /// likely included by mistake, and should be removed to avoid confusion.
 ///
+/// This rule flags `noqa` directives that fall into any of the following
+/// categories:
+/// - The directive's codes don't match any diagnostic raised on the line
+///   (`unused`).
+/// - The directive's codes correspond to rules that aren't enabled
+///   (`non-enabled`).
+/// - The directive repeats the same code more than once (`duplicated`).
+/// - The directive references a code that Ruff doesn't recognize
+///   (`unknown`).
+///
 /// ## Example
 /// ```python
 /// import foo  # noqa: F401
@@ -0,0 +1,144 @@
+use ruff_diagnostics::{AlwaysFixableViolation, Diagnostic, Edit, Fix};
+use ruff_macros::{derive_message_formats, ViolationMetadata};
+use ruff_python_ast::StringLike;
+use ruff_text_size::Ranged;
+
+use crate::checkers::ast::Checker;
+use crate::fix::edits::pad_start;
+
+/// ## What it does
+/// Checks for non-raw string literals that look like absolute Windows
+/// filesystem paths (e.g., `"C:\Users\name"`).
+///
+/// ## Why is this bad?
+/// Backslashes in a non-raw string literal are interpreted as the start of
+/// an escape sequence. In a Windows path, most of those backslash sequences
+/// aren't valid escapes (e.g., `\U`, `\n`), so the string doesn't contain
+/// the path it appears to. Prefixing the literal with `r` (or using forward
+/// slashes, which Windows also accepts) makes the backslashes literal.
+///
+/// ## Example
+/// ```python
+/// path = "C:\Users\name"
+/// ```
+///
+/// Use instead:
+/// ```python
+/// path = r"C:\Users\name"
+/// ```
+///
+/// ## Fix safety
+/// The fix is unsafe. It's marked as unsafe when the literal ends in a
+/// backslash, since a raw string literal can't end in an odd number of
+/// backslashes; in that case, the fix replaces the backslashes with forward
+/// slashes instead, which changes the string's value.
+#[derive(ViolationMetadata)]
+pub(crate) struct WindowsPathStringLiteral;
+
+impl AlwaysFixableViolation for WindowsPathStringLiteral {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        "String literal looks like a Windows path but is missing the `r` prefix".to_string()
+    }
+
+    fn fix_title(&self) -> String {
+        "Use a raw string literal".to_string()
+    }
+}
+
+/// RUF082
+pub(crate) fn windows_path_string_literal(checker: &Checker, string_like: StringLike) {
+    let StringLike::String(string) = string_like else {
+        return;
+    };
+
+    for part in string.value.iter() {
+        if part.flags.prefix().is_raw() {
+            continue;
+        }
+
+        let content_range = part.content_range();
+        let content = checker.locator().slice(content_range);
+
+        if !looks_like_windows_path(content) {
+            continue;
+        }
+
+        let mut diagnostic = Diagnostic::new(WindowsPathStringLiteral, part.range());
+
+        if !content.ends_with('\\') {
+            diagnostic.set_fix(Fix::unsafe_edit(Edit::insertion(
+                pad_start("r".to_string(), part.start(), checker.locator()),
+                part.start(),
+            )));
+        } else {
+            diagnostic.set_fix(Fix::unsafe_edit(Edit::range_replacement(
+                backslashes_to_forward_slashes(content),
+                content_range,
+            )));
+        }
+
+        checker.report_diagnostic(diagnostic);
+    }
+}
+
+/// Returns `true` if `content` (the text between a string literal's quotes) looks like an
+/// absolute Windows path: a drive letter followed by a colon and at least one backslash that
+/// isn't already escaped (i.e., a lone `\` rather than a `\\` pair).
+///
+/// A literal like `"C:\\Users\\name"` is already correctly escaped (its runtime value is
+/// `C:\Users\name`), so it's left alone.
+fn looks_like_windows_path(content: &str) -> bool {
+    let mut chars = content.chars();
+    let Some(drive) = chars.next() else {
+        return false;
+    };
+    if !drive.is_ascii_alphabetic() {
+        return false;
+    }
+    let rest = chars.as_str();
+    let Some(rest) = rest.strip_prefix(':') else {
+        return false;
+    };
+    if !rest.starts_with('\\') {
+        return false;
+    }
+
+    has_unescaped_backslash(rest)
+}
+
+/// Returns `true` if `content` contains a `\` that isn't paired with another `\` immediately
+/// after it (i.e., isn't part of an already-escaped `\\`).
+fn has_unescaped_backslash(content: &str) -> bool {
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            if chars.peek() == Some(&'\\') {
+                // A `\\` pair is already a valid, literal backslash escape; consume both
+                // characters and keep looking.
+                chars.next();
+            } else {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Converts each backslash in `content` to a forward slash, treating an already-escaped `\\`
+/// pair as a single logical backslash (and thus a single forward slash).
+fn backslashes_to_forward_slashes(content: &str) -> String {
+    let mut result = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            if chars.peek() == Some(&'\\') {
+                chars.next();
+            }
+            result.push('/');
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
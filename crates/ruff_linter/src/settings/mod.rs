@@ -7,7 +7,7 @@ use rustc_hash::FxHashSet;
 use std::fmt::{Display, Formatter};
 use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
-use types::CompiledPerFileTargetVersionList;
+use types::{CompiledPerFilePreviewList, CompiledPerFileTargetVersionList};
 
 use crate::codes::RuleCodePrefix;
 use ruff_macros::CacheKey;
@@ -234,7 +234,21 @@ pub struct LinterSettings {
     /// [`Path`] against these patterns, while falling back to `unresolved_target_version` if none
     /// of them match.
     pub per_file_target_version: CompiledPerFileTargetVersionList,
+    /// The global preview setting specified by the `preview` input option.
+    ///
+    /// If you have a `Checker` available, see its `preview_enabled` method instead.
+    ///
+    /// Otherwise, see [`LinterSettings::resolve_preview`] for a way to obtain the preview mode
+    /// for a given file, while respecting the overrides in `per_file_preview`.
     pub preview: PreviewMode,
+    /// Path-specific overrides to `preview`, so that preview rules can be rolled out on a
+    /// per-file (e.g. per-package) basis.
+    ///
+    /// If you have a `Checker` available, see its `preview_enabled` method instead.
+    ///
+    /// Otherwise, see [`LinterSettings::resolve_preview`] for a way to check a given [`Path`]
+    /// against these patterns, while falling back to `preview` if none of them match.
+    pub per_file_preview: CompiledPerFilePreviewList,
     pub explicit_preview_rules: bool,
 
     // Rule-specific settings
@@ -299,6 +313,7 @@ impl Display for LinterSettings {
                 self.unresolved_target_version,
                 self.per_file_target_version,
                 self.preview,
+                self.per_file_preview,
                 self.explicit_preview_rules,
                 self.extension | debug,
 
@@ -450,6 +465,7 @@ impl LinterSettings {
             pyupgrade: pyupgrade::settings::Settings::default(),
             ruff: ruff::settings::Settings::default(),
             preview: PreviewMode::default(),
+            per_file_preview: CompiledPerFilePreviewList::default(),
             explicit_preview_rules: false,
             extension: ExtensionMapping::default(),
             typing_extensions: true,
@@ -472,6 +488,14 @@ impl LinterSettings {
             .is_match(path)
             .map_or(self.unresolved_target_version, TargetVersion::from)
     }
+
+    /// Resolve whether preview mode is enabled for linting a given file.
+    ///
+    /// This method respects the per-file overrides in [`LinterSettings::per_file_preview`] and
+    /// falls back on [`LinterSettings::preview`] if none of the override patterns match.
+    pub fn resolve_preview(&self, path: &Path) -> PreviewMode {
+        self.per_file_preview.is_match(path).unwrap_or(self.preview)
+    }
 }
 
 impl Default for LinterSettings {
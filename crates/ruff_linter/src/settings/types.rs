@@ -855,6 +855,42 @@ impl Display for CompiledPerFileTargetVersionList {
     }
 }
 
+/// Contains a preview mode override for a given glob pattern.
+///
+/// See [`PerFile`] for details of the representation.
+#[derive(Debug, Clone)]
+pub struct PerFilePreview(PerFile<PreviewMode>);
+
+impl PerFilePreview {
+    pub fn new(pattern: String, preview: PreviewMode, project_root: Option<&Path>) -> Self {
+        Self(PerFile::new(pattern, project_root, preview))
+    }
+}
+
+#[derive(CacheKey, Clone, Debug, Default)]
+pub struct CompiledPerFilePreviewList(CompiledPerFileList<PreviewMode>);
+
+impl CompiledPerFilePreviewList {
+    /// Given a list of [`PerFilePreview`] patterns, create a compiled set of globs.
+    ///
+    /// Returns an error if either of the glob patterns cannot be parsed.
+    pub fn resolve(per_file_previews: Vec<PerFilePreview>) -> Result<Self> {
+        Ok(Self(CompiledPerFileList::resolve(
+            per_file_previews.into_iter().map(|preview| preview.0),
+        )?))
+    }
+
+    pub fn is_match(&self, path: &Path) -> Option<PreviewMode> {
+        self.0.iter_matches(path, "Setting preview mode").next().copied()
+    }
+}
+
+impl Display for CompiledPerFilePreviewList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
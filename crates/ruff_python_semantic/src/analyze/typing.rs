@@ -31,6 +31,7 @@ pub enum Callable {
     TypedDict,
     MypyExtension,
     TypeAliasType,
+    AssertType,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -1,7 +1,7 @@
 use std::path::Path;
 
 use bitflags::bitflags;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use ruff_python_ast::helpers::from_relative_import;
 use ruff_python_ast::name::{QualifiedName, UnqualifiedName};
@@ -1333,6 +1333,20 @@ impl<'a> SemanticModel<'a> {
         &mut self.scopes[self.scope_id]
     }
 
+    /// Returns a tuple of the name and ID of all bindings defined in the scope with the given
+    /// [`ScopeId`], without requiring the caller to index into [`SemanticModel::scopes`] directly.
+    pub fn scope_bindings(
+        &self,
+        scope_id: ScopeId,
+    ) -> impl Iterator<Item = (&'a str, BindingId)> + '_ {
+        self.scopes[scope_id].bindings()
+    }
+
+    /// Returns the [`ScopeKind`] of the scope with the given [`ScopeId`].
+    pub fn scope_kind(&self, scope_id: ScopeId) -> &ScopeKind<'a> {
+        &self.scopes[scope_id].kind
+    }
+
     /// Returns an iterator over all scopes, starting from the current [`Scope`].
     pub fn current_scopes(&self) -> impl Iterator<Item = &Scope<'a>> {
         self.scopes.ancestors(self.scope_id)
@@ -1343,6 +1357,32 @@ impl<'a> SemanticModel<'a> {
         self.scopes.ancestor_ids(self.scope_id)
     }
 
+    /// Returns the set of [`BindingId`]s, bound in scopes enclosing `scope_id`, that are
+    /// referenced from within `scope_id` or one of its descendants.
+    ///
+    /// In other words, this returns the free variables that the function, lambda, or
+    /// comprehension at `scope_id` closes over.
+    pub fn free_variables(&self, scope_id: ScopeId) -> FxHashSet<BindingId> {
+        let mut free_variables = FxHashSet::default();
+
+        for enclosing_scope in self.scopes.ancestors(scope_id).skip(1) {
+            for (_, binding_id) in enclosing_scope.bindings() {
+                let binding = &self.bindings[binding_id];
+                let is_referenced_within = binding.references().any(|reference_id| {
+                    let reference_scope_id = self.resolved_references[reference_id].scope_id();
+                    self.scopes
+                        .ancestor_ids(reference_scope_id)
+                        .any(|id| id == scope_id)
+                });
+                if is_referenced_within {
+                    free_variables.insert(binding_id);
+                }
+            }
+        }
+
+        free_variables
+    }
+
     /// Returns the parent of the given [`Scope`], if any.
     pub fn parent_scope(&self, scope: &Scope) -> Option<&Scope<'a>> {
         scope.parent.map(|scope_id| &self.scopes[scope_id])
@@ -1563,6 +1603,11 @@ impl<'a> SemanticModel<'a> {
     /// Unlike `global` declarations, for which the scope is unambiguous, Python requires that
     /// `nonlocal` declarations refer to the closest enclosing scope that contains a binding for
     /// the given name.
+    ///
+    /// Only `Class` and `Module` scopes are excluded from the search below; `Generator`,
+    /// `Lambda`, and `Type` scopes don't need to be, since none of them can contain a nested
+    /// `def` &mdash; their bodies are restricted to expressions &mdash; so they can never appear
+    /// as an ancestor of the scope in which the `nonlocal` statement itself lives.
     pub fn nonlocal(&self, name: &str) -> Option<(ScopeId, BindingId)> {
         self.scopes
             .ancestor_ids(self.scope_id)
@@ -1833,6 +1878,11 @@ impl<'a> SemanticModel<'a> {
         self.flags.intersects(SemanticModelFlags::TYPE_DEFINITION)
     }
 
+    /// Return `true` if the model is in a function's return type annotation.
+    pub const fn in_return_annotation(&self) -> bool {
+        self.flags.intersects(SemanticModelFlags::RETURN_ANNOTATION)
+    }
+
     /// Return `true` if the model is visiting a "string type definition"
     /// that was previously deferred when initially traversing the AST
     pub const fn in_string_type_definition(&self) -> bool {
@@ -1924,6 +1974,25 @@ impl<'a> SemanticModel<'a> {
         self.flags.intersects(SemanticModelFlags::TYPE_ALIAS)
     }
 
+    /// Return `true` if the model is in the slice of a subscript expression that is itself
+    /// within the value expression of a type alias.
+    pub const fn in_type_alias_subscript_slice(&self) -> bool {
+        self.flags
+            .intersects(SemanticModelFlags::TYPE_ALIAS_SUBSCRIPT_SLICE)
+    }
+
+    /// Return the [`Decorator`]s of the innermost enclosing function or class, if any.
+    ///
+    /// Returns an empty slice if the current scope is not a function or class scope (e.g., at
+    /// module level, or inside a lambda or generator expression).
+    pub fn current_decorator_list(&self) -> &'a [ast::Decorator] {
+        match self.current_scope().kind {
+            ScopeKind::Function(ast::StmtFunctionDef { decorator_list, .. })
+            | ScopeKind::Class(ast::StmtClassDef { decorator_list, .. }) => decorator_list,
+            _ => &[],
+        }
+    }
+
     /// Return `true` if the model is in an exception handler.
     pub const fn in_exception_handler(&self) -> bool {
         self.flags.intersects(SemanticModelFlags::EXCEPTION_HANDLER)
@@ -2568,6 +2637,29 @@ bitflags! {
 
         /// The context is in any type alias.
         const TYPE_ALIAS = Self::ANNOTATED_TYPE_ALIAS.bits() | Self::DEFERRED_TYPE_ALIAS.bits();
+
+        /// The model is in the slice of a subscript expression that is itself within the value
+        /// expression of a type alias.
+        ///
+        /// For example:
+        /// ```python
+        /// type OptList = list[int]  # We're visiting `int`, the slice of `list[int]`
+        /// ```
+        const TYPE_ALIAS_SUBSCRIPT_SLICE = 1 << 29;
+
+        /// The model is visiting a function's return type annotation.
+        ///
+        /// For example, the model might be visiting `int` in:
+        /// ```python
+        /// def foo() -> int:
+        ///     ...
+        /// ```
+        ///
+        /// This is distinct from [`ANNOTATION`], which is also set while visiting a
+        /// parameter's annotation.
+        ///
+        /// [`ANNOTATION`]: SemanticModelFlags::ANNOTATION
+        const RETURN_ANNOTATION = 1 << 30;
     }
 }
 
@@ -29,9 +29,9 @@ use ruff_linter::rules::{flake8_import_conventions, isort, pycodestyle};
 use ruff_linter::settings::fix_safety_table::FixSafetyTable;
 use ruff_linter::settings::rule_table::RuleTable;
 use ruff_linter::settings::types::{
-    CompiledPerFileIgnoreList, CompiledPerFileTargetVersionList, ExtensionMapping, FilePattern,
-    FilePatternSet, GlobPath, OutputFormat, PerFileIgnore, PerFileTargetVersion, PreviewMode,
-    RequiredVersion, UnsafeFixes,
+    CompiledPerFileIgnoreList, CompiledPerFilePreviewList, CompiledPerFileTargetVersionList,
+    ExtensionMapping, FilePattern, FilePatternSet, GlobPath, OutputFormat, PerFileIgnore,
+    PerFileTargetVersion, PreviewMode, RequiredVersion, UnsafeFixes,
 };
 use ruff_linter::settings::{
     LinterSettings, TargetVersion, DEFAULT_SELECTORS, DUMMY_VARIABLE_RGX, TASK_TAGS,
@@ -281,6 +281,10 @@ impl Configuration {
                 exclude: FilePatternSet::try_from_iter(lint.exclude.unwrap_or_default())?,
                 extension: self.extension.unwrap_or_default(),
                 preview: lint_preview,
+                // TODO: expose this via a `per-file-preview` configuration option; for now this
+                // is only settable by constructing `LinterSettings` directly (e.g. from an API
+                // consumer such as an LSP).
+                per_file_preview: CompiledPerFilePreviewList::default(),
                 unresolved_target_version: linter_target_version,
                 per_file_target_version,
                 project_root: project_root.to_path_buf(),
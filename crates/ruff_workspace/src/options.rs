@@ -2098,6 +2098,37 @@ pub struct Flake8TypeCheckingOptions {
     )]
     pub runtime_evaluated_decorators: Option<Vec<String>>,
 
+    /// Treat certain calls used as `typing.Annotated` metadata as
+    /// runtime-required, in addition to the annotation's first (type)
+    /// argument.
+    ///
+    /// By default, only the first argument to `Annotated` is treated as
+    /// runtime-required; imports used exclusively in the remaining metadata
+    /// arguments are free to be moved into type-checking blocks. Some
+    /// frameworks, however, rely on objects placed in that metadata being
+    /// available at runtime. For example, FastAPI evaluates `Depends(...)`
+    /// markers in parameter annotations:
+    ///
+    /// ```python
+    /// from typing import Annotated
+    ///
+    /// from fastapi import Depends
+    ///
+    /// def handler(user: Annotated[User, Depends(get_user)]): ...
+    /// ```
+    ///
+    /// Adding `"fastapi.Depends"` to this list ensures that the `Depends`
+    /// import (and any names referenced in its arguments) is not moved into
+    /// an `if TYPE_CHECKING:` block.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            runtime-required-annotated-metadata = ["fastapi.Depends"]
+        "#
+    )]
+    pub runtime_required_annotated_metadata: Option<Vec<String>>,
+
     /// Whether to add quotes around type annotations, if doing so would allow
     /// the corresponding import to be moved into a type-checking block.
     ///
@@ -2159,6 +2190,9 @@ impl Flake8TypeCheckingOptions {
                 .unwrap_or_else(|| vec!["typing".to_string()]),
             runtime_required_base_classes: self.runtime_evaluated_base_classes.unwrap_or_default(),
             runtime_required_decorators: self.runtime_evaluated_decorators.unwrap_or_default(),
+            runtime_required_annotated_metadata: self
+                .runtime_required_annotated_metadata
+                .unwrap_or_default(),
             quote_annotations: self.quote_annotations.unwrap_or_default(),
         }
     }
@@ -3205,6 +3239,20 @@ pub struct PyflakesOptions {
         example = r#"allowed-unused-imports = ["hvplot.pandas"]"#
     )]
     pub allowed_unused_imports: Option<Vec<String>>,
+
+    /// Whether an import that's only referenced from a `>>>`-style doctest example in its
+    /// module's docstring should be considered used.
+    ///
+    /// Doctest examples aren't executed by the linter, so names used there aren't tracked as
+    /// real references by default; enabling this option trades some risk of missing a
+    /// genuinely dead import for fewer false positives on modules whose imports exist mainly
+    /// to support documentation examples.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = "allow-unused-imports-in-doctests = true"
+    )]
+    pub allow_unused_imports_in_doctests: Option<bool>,
 }
 
 impl PyflakesOptions {
@@ -3212,6 +3260,9 @@ impl PyflakesOptions {
         pyflakes::settings::Settings {
             extend_generics: self.extend_generics.unwrap_or_default(),
             allowed_unused_imports: self.allowed_unused_imports.unwrap_or_default(),
+            allow_unused_imports_in_doctests: self
+                .allow_unused_imports_in_doctests
+                .unwrap_or_default(),
         }
     }
 }
@@ -3298,6 +3349,11 @@ pub struct PylintOptions {
         example = r"max-nested-blocks = 10"
     )]
     pub max_nested_blocks: Option<usize>,
+
+    /// Maximum number of `global` names allowed within a function or method body
+    /// (see `PLR0918`).
+    #[option(default = r"5", value_type = "int", example = r"max-globals = 10")]
+    pub max_globals: Option<usize>,
 }
 
 impl PylintOptions {
@@ -3322,6 +3378,7 @@ impl PylintOptions {
                 .unwrap_or(defaults.max_public_methods),
             max_locals: self.max_locals.unwrap_or(defaults.max_locals),
             max_nested_blocks: self.max_nested_blocks.unwrap_or(defaults.max_nested_blocks),
+            max_globals: self.max_globals.unwrap_or(defaults.max_globals),
         }
     }
 }